@@ -1,14 +1,49 @@
-use auditd_parser::AuditdRecord;
-use std::io::{self, BufRead};
+use auditd_parser::{AuditLogReader, TimestampFormat};
+use std::env;
+use std::io;
+use std::process::ExitCode;
 
-fn main() {
-    let mut line = String::new();
-    let stdin = io::stdin();
-    stdin.lock().read_line(&mut line).unwrap();
-    let auditd_record = line.parse::<AuditdRecord>();
-    let result = match &auditd_record {
-        Ok(record) => serde_json::to_string_pretty(&record).unwrap(),
-        error @ Err(_) => serde_json::to_string_pretty(error).unwrap(),
+const USAGE: &str = "usage: cli [--timestamp-format seconds|millis|rfc3339]";
+
+fn parse_timestamp_format(mut args: impl Iterator<Item = String>) -> Option<TimestampFormat> {
+    let format = match args.next().as_deref() {
+        None => TimestampFormat::default(),
+        Some("--timestamp-format") => match args.next()?.as_str() {
+            "seconds" => TimestampFormat::UnixSeconds,
+            "millis" => TimestampFormat::UnixMillis,
+            "rfc3339" => TimestampFormat::Rfc3339,
+            _ => return None,
+        },
+        Some(_) => return None,
     };
-    println!("{result}")
+
+    args.next().is_none().then_some(format)
+}
+
+fn main() -> ExitCode {
+    let Some(timestamp_format) = parse_timestamp_format(env::args().skip(1)) else {
+        eprintln!("{USAGE}");
+        return ExitCode::FAILURE;
+    };
+
+    let stdin = io::stdin();
+    for read_record in AuditLogReader::new(stdin.lock()).skip_comments(true) {
+        let read_record = match read_record {
+            Ok(read_record) => read_record,
+            Err(err) => {
+                eprintln!("error: failed to read stdin: {err}");
+                return ExitCode::FAILURE;
+            }
+        };
+        let result = match &read_record.record {
+            Ok(record) => {
+                serde_json::to_string_pretty(&record.with_timestamp_format(timestamp_format))
+                    .unwrap()
+            }
+            error @ Err(_) => serde_json::to_string_pretty(error).unwrap(),
+        };
+        println!("{result}");
+    }
+
+    ExitCode::SUCCESS
 }
@@ -0,0 +1,391 @@
+use std::collections::{HashSet, VecDeque};
+use std::time::Duration;
+
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+use crate::{AuditTimestamp, AuditdRecord, RecordType};
+#[cfg(feature = "serde")]
+use crate::{TimestampFormat, WithTimestampFormat};
+
+/// Events older than this (compared to the newest record read) are considered complete
+const DEFAULT_TIME_WINDOW: Duration = Duration::from_secs(2);
+/// Number of emitted events remembered, so their late records do not start new events
+const EMITTED_EVENTS_CAPACITY: usize = 1024;
+
+/// Node, timestamp and id shared by the records of an event
+type EventKey = (Option<String>, Option<AuditTimestamp>, Option<u64>);
+
+/// Audit event, made of every record sharing the same node and `msg=audit(timestamp:id)`.
+#[derive(Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct AuditEvent {
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
+    pub node: Option<String>,
+    /// Timestamp shared by all the records of the event, `None` for records logged as `msg=?`
+    pub timestamp: Option<AuditTimestamp>,
+    /// Event identifier (serial number), `None` for records logged as `msg=?`
+    pub id: Option<u64>,
+    /// Records of the event, in the order they were read. The `EOE` record is not included.
+    pub records: Vec<AuditdRecord>,
+}
+
+impl AuditEvent {
+    fn new(record: &AuditdRecord) -> Self {
+        Self {
+            node: record.node.clone(),
+            timestamp: record.timestamp,
+            id: record.id,
+            records: Vec::new(),
+        }
+    }
+
+    /// Records logged without id (as `msg=?`) can not be grouped, so they are events on their own
+    fn contains(&self, record: &AuditdRecord) -> bool {
+        self.id.is_some()
+            && self.id == record.id
+            && self.timestamp == record.timestamp
+            && self.node == record.node
+    }
+
+    fn key(&self) -> EventKey {
+        (self.node.clone(), self.timestamp, self.id)
+    }
+
+    /// Returns the first record of the given type.
+    #[must_use]
+    pub fn record(&self, record_type: &RecordType) -> Option<&AuditdRecord> {
+        self.records
+            .iter()
+            .find(|record| record.record_type == *record_type)
+    }
+
+    /// Serializes the event with its timestamps in the given format, such as RFC 3339.
+    #[cfg(feature = "serde")]
+    #[must_use]
+    pub fn with_timestamp_format(&self, format: TimestampFormat) -> WithTimestampFormat<'_, Self> {
+        WithTimestampFormat::new(self, format)
+    }
+
+    /// Returns all the records of the given type, such as the `PATH` records of a syscall.
+    pub fn records_of_type<'a>(
+        &'a self,
+        record_type: &'a RecordType,
+    ) -> impl Iterator<Item = &'a AuditdRecord> {
+        self.records
+            .iter()
+            .filter(move |record| record.record_type == *record_type)
+    }
+}
+
+struct PendingEvent {
+    event: AuditEvent,
+    complete: bool,
+    /// Number of records read by the aggregator when the last record of the event was added
+    last_record: u64,
+}
+
+/// Groups records into [`AuditEvent`]s.
+///
+/// An event is complete when:
+/// - its `EOE` record is read,
+/// - it is a single-record userspace event (such as `USER_LOGIN`),
+/// - more than the time window has passed between the event and the newest record read
+///   (2 seconds by default, using the timestamps of the records),
+/// - or the record window number of records of other events have been read since its last record
+///   (disabled by default).
+///
+/// Events are emitted in the order in which their first record was read, so a complete
+/// event waits for older incomplete events of interleaved streams.
+///
+/// Records read after their event was emitted (such as an `EOE` record read after the time
+/// window) are dropped, as are `EOE` records of unknown events, so no event is emitted twice
+/// or without records. Only the last 1024 emitted events are remembered.
+pub struct EventAggregator {
+    events: VecDeque<PendingEvent>,
+    /// Keys of the last emitted events, oldest first
+    emitted: VecDeque<EventKey>,
+    emitted_keys: HashSet<EventKey>,
+    records_read: u64,
+    record_window: Option<u64>,
+    time_window: Option<Duration>,
+}
+
+impl EventAggregator {
+    #[must_use]
+    pub fn new() -> Self {
+        Self {
+            events: VecDeque::new(),
+            emitted: VecDeque::new(),
+            emitted_keys: HashSet::new(),
+            records_read: 0,
+            record_window: None,
+            time_window: Some(DEFAULT_TIME_WINDOW),
+        }
+    }
+
+    /// Completes events once this number of records have been read after their last record.
+    #[must_use]
+    pub fn record_window(mut self, record_window: Option<u64>) -> Self {
+        self.record_window = record_window;
+        self
+    }
+
+    /// Completes events once a record this much newer than them is read.
+    #[must_use]
+    pub fn time_window(mut self, time_window: Option<Duration>) -> Self {
+        self.time_window = time_window;
+        self
+    }
+
+    /// Adds a record to its event, returning the events that were completed, in order.
+    pub fn push(&mut self, record: AuditdRecord) -> Vec<AuditEvent> {
+        let is_eoe = record.record_type == RecordType::Eoe;
+        let is_single_record = is_single_record_event(&record.record_type) || record.id.is_none();
+        let timestamp = record.timestamp;
+
+        // Interleaved events are usually the most recent ones, so search from the back
+        let index = if let Some(index) = self
+            .events
+            .iter()
+            .rposition(|pending| pending.event.contains(&record))
+        {
+            index
+        } else if is_eoe || self.was_emitted(&record) {
+            return Vec::new();
+        } else {
+            self.events.push_back(PendingEvent {
+                event: AuditEvent::new(&record),
+                complete: false,
+                last_record: 0,
+            });
+            self.events.len() - 1
+        };
+
+        self.records_read += 1;
+        let pending = &mut self.events[index];
+        pending.last_record = self.records_read;
+        if is_eoe || is_single_record {
+            pending.complete = true;
+        }
+        if !is_eoe {
+            pending.event.records.push(record);
+        }
+
+        self.complete_expired(timestamp);
+        self.pop_completed()
+    }
+
+    /// Completes every pending event, such as when the end of the log is reached.
+    pub fn flush(&mut self) -> Vec<AuditEvent> {
+        let events: Vec<_> = self.events.drain(..).map(|pending| pending.event).collect();
+        for event in &events {
+            self.remember_emitted(event);
+        }
+        events
+    }
+
+    fn complete_expired(&mut self, newest_timestamp: Option<AuditTimestamp>) {
+        for pending in &mut self.events {
+            let record_window_expired = self.record_window.is_some_and(|record_window| {
+                self.records_read - pending.last_record >= record_window
+            });
+            // All the records of an event share its timestamp
+            let time_window_expired =
+                match (self.time_window, newest_timestamp, pending.event.timestamp) {
+                    (Some(time_window), Some(newest_timestamp), Some(timestamp)) => {
+                        let elapsed = newest_timestamp
+                            .duration_since_epoch()
+                            .saturating_sub(timestamp.duration_since_epoch());
+                        elapsed > time_window
+                    }
+                    _ => false,
+                };
+
+            if record_window_expired || time_window_expired {
+                pending.complete = true;
+            }
+        }
+    }
+
+    fn pop_completed(&mut self) -> Vec<AuditEvent> {
+        let mut completed = Vec::new();
+        while self.events.front().is_some_and(|pending| pending.complete) {
+            if let Some(pending) = self.events.pop_front() {
+                self.remember_emitted(&pending.event);
+                completed.push(pending.event);
+            }
+        }
+        completed
+    }
+
+    fn was_emitted(&self, record: &AuditdRecord) -> bool {
+        record.id.is_some()
+            && self
+                .emitted_keys
+                .contains(&(record.node.clone(), record.timestamp, record.id))
+    }
+
+    fn remember_emitted(&mut self, event: &AuditEvent) {
+        // Events without id can not have late records
+        if event.id.is_none() {
+            return;
+        }
+
+        let key = event.key();
+        if self.emitted_keys.insert(key.clone()) {
+            self.emitted.push_back(key);
+        }
+        if self.emitted.len() > EMITTED_EVENTS_CAPACITY {
+            if let Some(oldest) = self.emitted.pop_front() {
+                self.emitted_keys.remove(&oldest);
+            }
+        }
+    }
+}
+
+impl Default for EventAggregator {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// Userspace and daemon messages are logged as a single record, without an `EOE` record.
+// Ref: https://github.com/torvalds/linux/blob/561c80369df0733ba0574882a1635287b20f9de2/include/uapi/linux/audit.h
+fn is_single_record_event(record_type: &RecordType) -> bool {
+    record_type
+        .number()
+        .is_some_and(|number| matches!(number, 1100..=1299 | 2100..=2999))
+}
+
+#[cfg(test)]
+mod tests {
+    use rstest::rstest;
+
+    use super::*;
+
+    fn record(record_type: &str, timestamp: u64, id: u64) -> AuditdRecord {
+        format!(
+            "type={record_type} msg=audit({}.{:03}:{id}): key=value",
+            timestamp / 1000,
+            timestamp % 1000
+        )
+        .parse()
+        .unwrap()
+    }
+
+    fn event_ids(events: &[AuditEvent]) -> Vec<u64> {
+        events.iter().filter_map(|event| event.id).collect()
+    }
+
+    #[test]
+    fn test_completes_on_eoe() {
+        let mut aggregator = EventAggregator::new();
+        assert!(aggregator.push(record("SYSCALL", 1000, 1)).is_empty());
+        assert!(aggregator.push(record("CWD", 1000, 1)).is_empty());
+        assert!(aggregator.push(record("PATH", 1000, 1)).is_empty());
+
+        let events = aggregator.push(record("EOE", 1000, 1));
+        assert_eq!(events.len(), 1);
+        let record_types: Vec<_> = events[0]
+            .records
+            .iter()
+            .map(|record| record.record_type.to_string())
+            .collect();
+        assert_eq!(record_types, vec!["SYSCALL", "CWD", "PATH"]);
+        assert!(events[0].record(&RecordType::Cwd).is_some());
+    }
+
+    #[test]
+    fn test_completes_single_record_userspace_events() {
+        let mut aggregator = EventAggregator::new();
+        let events = aggregator.push(record("USER_LOGIN", 1000, 1));
+        assert_eq!(event_ids(&events), vec![1]);
+    }
+
+    #[test]
+    fn test_emits_interleaved_events_in_order() {
+        let mut aggregator = EventAggregator::new();
+        assert!(aggregator.push(record("SYSCALL", 1000, 1)).is_empty());
+        assert!(aggregator.push(record("SYSCALL", 1000, 2)).is_empty());
+        // The second event is complete, but it waits for the first one
+        assert!(aggregator.push(record("EOE", 1000, 2)).is_empty());
+
+        let events = aggregator.push(record("EOE", 1000, 1));
+        assert_eq!(event_ids(&events), vec![1, 2]);
+    }
+
+    #[rstest]
+    #[case::within_window(2000, vec![])]
+    #[case::after_window(3001, vec![1])]
+    fn test_completes_after_time_window(#[case] timestamp: u64, #[case] expected: Vec<u64>) {
+        let mut aggregator = EventAggregator::new();
+        assert!(aggregator.push(record("CONFIG_CHANGE", 1000, 1)).is_empty());
+
+        let events = aggregator.push(record("SYSCALL", timestamp, 2));
+        assert_eq!(event_ids(&events), expected);
+    }
+
+    #[test]
+    fn test_completes_after_record_window() {
+        let mut aggregator = EventAggregator::new()
+            .time_window(None)
+            .record_window(Some(2));
+        assert!(aggregator.push(record("CONFIG_CHANGE", 1000, 1)).is_empty());
+        // A single record read after the first event is within the window
+        assert!(aggregator.push(record("SYSCALL", 1000, 2)).is_empty());
+
+        let events = aggregator.push(record("CWD", 1000, 2));
+        assert_eq!(event_ids(&events), vec![1]);
+    }
+
+    #[test]
+    fn test_drops_records_of_emitted_events() {
+        let mut aggregator = EventAggregator::new();
+        assert!(aggregator.push(record("SYSCALL", 1000, 1)).is_empty());
+        let events = aggregator.push(record("SYSCALL", 3001, 2));
+        assert_eq!(event_ids(&events), vec![1]);
+
+        // The first event expired before its last records were read
+        assert!(aggregator.push(record("PATH", 1000, 1)).is_empty());
+        assert!(aggregator.push(record("EOE", 1000, 1)).is_empty());
+
+        let events = aggregator.push(record("EOE", 3001, 2));
+        assert_eq!(event_ids(&events), vec![2]);
+        assert_eq!(events[0].records.len(), 1);
+        assert!(aggregator.flush().is_empty());
+    }
+
+    #[test]
+    fn test_drops_eoe_of_unknown_events() {
+        let mut aggregator = EventAggregator::new();
+        assert!(aggregator.push(record("EOE", 1000, 1)).is_empty());
+        assert!(aggregator.flush().is_empty());
+    }
+
+    #[test]
+    fn test_completes_records_without_id() {
+        let mut aggregator = EventAggregator::new();
+        let record = "type=UNKNOWN[1329] msg=?".parse::<AuditdRecord>().unwrap();
+        let events = aggregator.push(record);
+        assert_eq!(events.len(), 1);
+        assert_eq!(events[0].id, None);
+        assert_eq!(events[0].records.len(), 1);
+    }
+
+    #[test]
+    fn test_groups_by_node() {
+        let mut aggregator = EventAggregator::new();
+        let mut other_node = record("SYSCALL", 1000, 1);
+        other_node.node = Some("other".into());
+        aggregator.push(record("SYSCALL", 1000, 1));
+        aggregator.push(other_node);
+
+        let events = aggregator.flush();
+        assert_eq!(events.len(), 2);
+        assert_eq!(events[1].node, Some("other".into()));
+    }
+}
@@ -0,0 +1,203 @@
+use std::collections::BTreeMap;
+
+#[cfg(feature = "serde")]
+use serde::{
+    Deserialize, Deserializer, Serialize, Serializer,
+    de::{MapAccess, Visitor},
+    ser::SerializeMap,
+};
+
+/// Ordered multimap of fields, which keeps the order in which the fields were logged
+/// and every value of duplicated keys (such as stacked LSM `subj` fields).
+///
+/// Keys are owned strings by default, while the parser borrows them from the input.
+///
+/// When serialized, it is represented as a map with the entries in their original order.
+/// Duplicated keys are serialized as repeated map keys.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Fields<V, K = String> {
+    entries: Vec<(K, V)>,
+}
+
+impl<V, K> Fields<V, K> {
+    #[must_use]
+    pub fn new() -> Self {
+        Self {
+            entries: Vec::new(),
+        }
+    }
+
+    #[must_use]
+    pub fn len(&self) -> usize {
+        self.entries.len()
+    }
+
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.entries.is_empty()
+    }
+
+    /// Appends a value to the end of the fields, keeping any previous value of the same key.
+    pub fn insert(&mut self, key: impl Into<K>, value: V) {
+        self.entries.push((key.into(), value));
+    }
+}
+
+impl<V, K: AsRef<str>> Fields<V, K> {
+    /// Returns the first value of the given key.
+    #[must_use]
+    pub fn get(&self, key: &str) -> Option<&V> {
+        self.entries
+            .iter()
+            .find(|(entry_key, _)| entry_key.as_ref() == key)
+            .map(|(_, value)| value)
+    }
+
+    /// Returns all the values of the given key, in the order they were logged.
+    pub fn get_all<'a>(&'a self, key: &'a str) -> impl Iterator<Item = &'a V> {
+        self.entries
+            .iter()
+            .filter(move |(entry_key, _)| entry_key.as_ref() == key)
+            .map(|(_, value)| value)
+    }
+
+    #[must_use]
+    pub fn contains_key(&self, key: &str) -> bool {
+        self.get(key).is_some()
+    }
+
+    /// Iterates over all the fields, in the order they were logged.
+    pub fn iter(&self) -> impl Iterator<Item = (&str, &V)> {
+        self.entries
+            .iter()
+            .map(|(key, value)| (key.as_ref(), value))
+    }
+
+    pub fn keys(&self) -> impl Iterator<Item = &str> {
+        self.iter().map(|(key, _)| key)
+    }
+
+    pub fn values(&self) -> impl Iterator<Item = &V> {
+        self.iter().map(|(_, value)| value)
+    }
+}
+
+impl<V, K> Default for Fields<V, K> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<V, K> FromIterator<(K, V)> for Fields<V, K> {
+    fn from_iter<T: IntoIterator<Item = (K, V)>>(iter: T) -> Self {
+        Self {
+            entries: iter.into_iter().collect(),
+        }
+    }
+}
+
+impl<V, K> Extend<(K, V)> for Fields<V, K> {
+    fn extend<T: IntoIterator<Item = (K, V)>>(&mut self, iter: T) {
+        self.entries.extend(iter);
+    }
+}
+
+impl<V, K> IntoIterator for Fields<V, K> {
+    type Item = (K, V);
+    type IntoIter = std::vec::IntoIter<(K, V)>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.entries.into_iter()
+    }
+}
+
+impl<T: Into<K>, V, K, const N: usize> From<[(T, V); N]> for Fields<V, K> {
+    fn from(value: [(T, V); N]) -> Self {
+        value
+            .into_iter()
+            .map(|(key, value)| (key.into(), value))
+            .collect()
+    }
+}
+
+impl<V> From<BTreeMap<String, V>> for Fields<V> {
+    fn from(value: BTreeMap<String, V>) -> Self {
+        value.into_iter().collect()
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<V: Serialize, K: AsRef<str>> Serialize for Fields<V, K> {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        let mut map = serializer.serialize_map(Some(self.len()))?;
+        for (key, value) in self.iter() {
+            map.serialize_entry(key, value)?;
+        }
+        map.end()
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<'de, V: Deserialize<'de>> Deserialize<'de> for Fields<V> {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        struct FieldsVisitor<V>(std::marker::PhantomData<V>);
+
+        impl<'de, V: Deserialize<'de>> Visitor<'de> for FieldsVisitor<V> {
+            type Value = Fields<V>;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter) -> std::fmt::Result {
+                formatter.write_str("a map of fields")
+            }
+
+            fn visit_map<A: MapAccess<'de>>(self, mut access: A) -> Result<Self::Value, A::Error> {
+                let mut fields = Fields::new();
+                while let Some((key, value)) = access.next_entry::<String, V>()? {
+                    fields.insert(key, value);
+                }
+                Ok(fields)
+            }
+        }
+
+        deserializer.deserialize_map(FieldsVisitor(std::marker::PhantomData))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn fields() -> Fields<&'static str> {
+        Fields::from([("subj", "first"), ("pid", "1"), ("subj", "second")])
+    }
+
+    #[test]
+    fn test_get_returns_first_value() {
+        assert_eq!(fields().get("subj"), Some(&"first"));
+        assert_eq!(fields().get("pid"), Some(&"1"));
+        assert_eq!(fields().get("missing"), None);
+    }
+
+    #[test]
+    fn test_get_all_returns_every_value_in_order() {
+        let fields = fields();
+        let values: Vec<_> = fields.get_all("subj").collect();
+        assert_eq!(values, vec![&"first", &"second"]);
+        assert_eq!(fields.get_all("missing").count(), 0);
+    }
+
+    #[test]
+    fn test_iter_keeps_order_and_duplicates() {
+        let fields = fields();
+        let keys: Vec<_> = fields.keys().collect();
+        assert_eq!(keys, vec!["subj", "pid", "subj"]);
+    }
+
+    #[test]
+    fn test_insert_appends() {
+        let mut fields = fields();
+        fields.insert("subj", "third");
+        let values: Vec<_> = fields.get_all("subj").collect();
+        assert_eq!(values, vec![&"first", &"second", &"third"]);
+        assert_eq!(fields.len(), 4);
+    }
+}
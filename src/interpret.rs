@@ -1,29 +1,27 @@
+use std::borrow::Cow;
 use std::collections::BTreeMap;
 
-use arch::AuditArch;
 use bytes::Bytes;
-use errno::Errno;
-use field_type::FieldType;
-use mac_label::MacLabel;
-use nom::{Parser, combinator::all_consuming};
-use signal::Signal;
-use socket::SocketAddr;
+use syscall_arg::SyscallArg;
 use uid::Uid;
 
 use crate::{
-    AuditdRecord, FieldValue,
-    parser::{self, RawAuditdRecord},
+    AuditdRecord, FieldValue, Fields, RecordType,
+    parser::{self, RawAuditdRecord, RawFields, RawValue},
     record::Number,
 };
 
 mod arch;
 mod audit_flag;
+mod avc;
 mod capability;
 mod errno;
+mod execve;
 mod field_type;
 mod mac_label;
 mod mode;
 mod null;
+mod options;
 mod pam;
 mod perm;
 mod proctitle;
@@ -31,26 +29,97 @@ mod result;
 mod signal;
 mod socket;
 mod success;
+mod syscall;
+mod syscall_arg;
 mod uid;
 mod utils;
 
-impl From<RawAuditdRecord> for AuditdRecord {
-    fn from(value: RawAuditdRecord) -> Self {
-        let fields = value
-            .fields
-            .into_iter()
-            .map(|(field_name, field_value)| {
-                let field_value =
-                    interpret_field_value(&value.record_type, &field_name, field_value);
+pub use arch::AuditArch;
+pub use audit_flag::AuditFlag;
+pub use errno::Errno;
+pub use field_type::FieldType;
+pub use mac_label::{AppArmorLabel, AppArmorMode, MacLabel, SELinuxContext, SELinuxLevel};
+pub use mode::{Attribute, FileType, Mode, Permission};
+pub use options::{InterpretOptions, NameStyle, UidFormat};
+pub use result::AuditResult;
+pub use signal::Signal;
+#[cfg(feature = "serde")]
+pub(crate) use socket::serialize_socket_addr;
+pub use socket::{SocketAddr, SocketAddrLocal, SocketAddrNetlink};
+
+impl From<RawAuditdRecord<'_>> for AuditdRecord {
+    fn from(value: RawAuditdRecord<'_>) -> Self {
+        Self::from_raw_with(value, &InterpretOptions::default())
+    }
+}
+
+impl AuditdRecord {
+    /// Interprets a record parsed without copying its fields, with the given options.
+    pub fn from_raw_with(value: RawAuditdRecord<'_>, options: &InterpretOptions) -> Self {
+        let context = InterpretContext::new(&value.record_type, &value.fields, options);
+        // Only lines that were not valid UTF-8 have escaped bytes, as valid lines may contain
+        // the code points used to escape them
+        let invalid_utf8 = value.invalid_utf8;
+        // EXECVE arguments are reassembled into a single `argv` field
+        let argv = match value.record_type {
+            RecordType::Execve => execve::reassemble_argv(&value.fields, invalid_utf8),
+            _ => None,
+        };
+        let restore_string = |string: String| {
+            if invalid_utf8 {
+                restore_escaped_string(string)
+            } else {
+                string
+            }
+        };
+        let restore_bytes = |field_value: FieldValue| {
+            if invalid_utf8 {
+                restore_escaped_bytes(field_value)
+            } else {
+                field_value
+            }
+        };
+        let mut fields = Fields::new();
+        // Raw values have no bytes representation, so invalid bytes are replaced
+        let mut raw_fields = Fields::new();
+        for (field_name, field_value) in value.fields {
+            let field_name = restore_string(field_name.into_owned());
+            if options.keep_raw_values {
+                raw_fields.insert(
+                    field_name.clone(),
+                    restore_string(field_value.as_str().to_string()),
+                );
+            }
+
+            if argv.is_none() || !execve::is_arg_field(&field_name) {
+                let field_value = interpret_field_value(&context, &field_name, field_value);
+                fields.insert(field_name, restore_bytes(field_value));
+            }
+        }
+
+        if let Some(argv) = argv {
+            let cmdline = argv.join(" ");
+            fields.insert("argv", argv.into());
+            fields.insert("cmdline", cmdline.into());
+        }
 
-                (field_name, field_value)
-            })
-            .collect();
+        // Proctitle arguments are also joined, as auparse shows them
+        if value.record_type == RecordType::Proctitle {
+            if let Some(FieldValue::Array(args)) = fields.get("proctitle") {
+                let cmdline = args.join(" ");
+                fields.insert("cmdline", cmdline.into());
+            }
+        }
 
         let enrichment = value.enrichment.map(|enrichment| {
             enrichment
                 .into_iter()
-                .map(|(key, val)| (key, val.into()))
+                .map(|(key, val)| {
+                    (
+                        restore_string(key.into_owned()),
+                        restore_bytes(interpret_enrichment_value(val)),
+                    )
+                })
                 .collect()
         });
 
@@ -58,97 +127,278 @@ impl From<RawAuditdRecord> for AuditdRecord {
             record_type: value.record_type,
             timestamp: value.timestamp,
             id: value.id,
-            node: value.node,
+            node: value.node.map(Cow::into_owned),
             fields,
+            raw_fields,
             enrichment,
+            unparsed: value.unparsed.into_iter().map(Cow::into_owned).collect(),
+            warnings: value.warnings,
+        }
+    }
+}
+
+/// Lines that are not valid UTF-8 keep their invalid bytes escaped in the values,
+/// which are restored once interpreted, so they are kept as bytes instead of being lost.
+fn restore_escaped_bytes(field_value: FieldValue) -> FieldValue {
+    match field_value {
+        FieldValue::String(value) => match parser::unescape_bytes(&value) {
+            Some(bytes) => match String::from_utf8(bytes) {
+                Ok(value) => value.into(),
+                Err(err) => FieldValue::Bytes(err.into_bytes()),
+            },
+            None => value.into(),
+        },
+        // Keys and array items can only hold strings, so invalid bytes are replaced
+        FieldValue::Array(values) => values
+            .into_iter()
+            .map(restore_escaped_string)
+            .collect::<Vec<_>>()
+            .into(),
+        FieldValue::Map(fields) => fields
+            .into_iter()
+            .map(|(key, value)| (restore_escaped_string(key), restore_escaped_bytes(value)))
+            .collect::<Fields<_>>()
+            .into(),
+        field_value => field_value,
+    }
+}
+
+fn restore_escaped_string(value: String) -> String {
+    match parser::unescape_bytes(&value) {
+        Some(bytes) => String::from_utf8_lossy(&bytes).into_owned(),
+        None => value,
+    }
+}
+
+/// Record being interpreted, so the interpretation of a field can depend on its siblings
+/// (e.g. `syscall` numbers are resolved with the syscall table of the `arch` field).
+/// The siblings are resolved up front, so the fields can be consumed while interpreted.
+struct InterpretContext<'a> {
+    record_type: &'a RecordType,
+    arch: Option<AuditArch>,
+    success: Option<bool>,
+    syscall_number: Option<u32>,
+    /// Whether the fields have the ones parsed from the prefix of `SELinux` AVC records
+    avc_prefix: bool,
+    options: &'a InterpretOptions,
+}
+
+impl<'a> InterpretContext<'a> {
+    fn new(
+        record_type: &'a RecordType,
+        fields: &RawFields<'_>,
+        options: &'a InterpretOptions,
+    ) -> Self {
+        Self {
+            record_type,
+            arch: fields
+                .get("arch")
+                .and_then(|arch| parse_arch(arch.as_str())),
+            success: fields
+                .get("success")
+                .and_then(|success| success::resolve_success(success.as_str())),
+            syscall_number: fields
+                .get("syscall")
+                .and_then(|syscall| syscall.as_str().parse().ok()),
+            avc_prefix: matches!(record_type, RecordType::Avc | RecordType::UserAvc)
+                && fields.contains_key(parser::body::AVC_DECISION_KEY),
+            options,
         }
     }
+
+    fn arch(&self) -> Option<AuditArch> {
+        self.arch
+    }
+
+    /// Whether the syscall of the record succeeded, from its `success` field
+    fn success(&self) -> Option<bool> {
+        self.success
+    }
+
+    /// Name of the syscall of the record, resolved with its `arch` field
+    fn syscall(&self) -> Option<&'static str> {
+        syscall::resolve_syscall(self.arch?, self.syscall_number?)
+    }
 }
 
 // Based on https://github.com/linux-audit/audit-userspace/blob/747f67994b933fd70deed7d6f7cb0c40601f5bd1/auparse/interpret.c#L3325
-fn interpret_field_value(record_type: &str, field_name: &str, field_value: String) -> FieldValue {
-    if null::is_null_value(&field_value) {
+fn interpret_field_value(
+    context: &InterpretContext,
+    field_name: &str,
+    field_value: RawValue<'_>,
+) -> FieldValue {
+    if null::is_null_value(field_value.as_str()) {
         return FieldValue::Null;
     }
 
-    let Some(field_type) = FieldType::resolve(field_name) else {
+    let field_type =
+        if *context.record_type == RecordType::Execve && execve::is_arg_field(field_name) {
+            // EXECVE arguments that were not reassembled are kept, `a1` is not a syscall argument
+            Some(FieldType::Escaped)
+        } else if field_name == parser::body::AVC_PERMISSIONS_KEY {
+            // Only the permissions parsed from the AVC prefix are a list, not any `permissions` field
+            context.avc_prefix.then_some(FieldType::AvcPermissions)
+        } else {
+            FieldType::resolve(field_name)
+        };
+    let Some(field_type) = field_type else {
         // Defaults to leave the field uninterpreted
         // TODO: should we default to `FieldValue::Escaped`?
-        return field_value.into();
+        return field_value.into_string().into();
     };
 
+    if !context.options.is_enabled(field_type) {
+        return field_value.into_string().into();
+    }
+
     match field_type {
+        // Only escaped fields and proctitle care about the value being quoted
         FieldType::Escaped => interpret_escaped_field(field_value),
-        FieldType::Msg => interpret_msg_field(record_type, field_value),
-        FieldType::Uid | FieldType::Gid => interpret_uid_field(field_value),
-        FieldType::Exit => interpret_exit_field(field_value),
-        FieldType::CapabilityBitmap => interpret_cap_bitmap_field(field_value),
-        FieldType::SocketAddr => interpret_socket_addr_field(field_value),
-        FieldType::Perm => interpret_perm_field(field_value),
-        FieldType::Result => interpret_result_field(&field_value),
         FieldType::Proctitle => interpret_proctitle_field(field_value),
-        FieldType::Mode => interpret_mode_field(field_value),
-        FieldType::Signal => interpret_signal_field(field_value),
-        FieldType::List => interpret_list_field(field_value),
-        FieldType::Success => interpret_success_field(field_value),
-        FieldType::Errno => interpret_errno_field(field_value),
-        FieldType::MacLabel => interpret_mac_label_field(field_value),
-        FieldType::PAMGrantors => interpret_pam_grantors_field(&field_value),
-        FieldType::Arch => interpret_arch_field(&field_value),
+        FieldType::Msg => interpret_msg_field(context, field_value.into_string()),
+        FieldType::Uid => interpret_uid_field(
+            &context.options.uid_format,
+            false,
+            field_value.into_string(),
+        ),
+        FieldType::Gid => {
+            interpret_uid_field(&context.options.uid_format, true, field_value.into_string())
+        }
+        FieldType::Exit => interpret_exit_field(context.success(), field_value.into_string()),
+        FieldType::CapabilityBitmap => {
+            interpret_cap_bitmap_field(context.options, field_value.into_string())
+        }
+        FieldType::SocketAddr => {
+            interpret_socket_addr_field(context.options, field_value.into_string())
+        }
+        FieldType::Perm => interpret_perm_field(field_value.into_string()),
+        FieldType::Result => interpret_result_field(field_value.as_str()),
+        FieldType::Mode => interpret_mode_field(field_value.into_string()),
+        FieldType::Signal => interpret_signal_field(field_value.into_string()),
+        FieldType::List => interpret_list_field(field_value.into_string()),
+        FieldType::Success => interpret_success_field(field_value.into_string()),
+        FieldType::Errno => interpret_errno_field(field_value.into_string()),
+        FieldType::MacLabel => interpret_mac_label_field(field_value.into_string()),
+        FieldType::PAMGrantors => interpret_pam_grantors_field(field_value.as_str()),
+        FieldType::Arch => interpret_arch_field(field_value.as_str()),
+        FieldType::Syscall => interpret_syscall_field(context.arch(), field_value.into_string()),
+        FieldType::SyscallArg(index) => {
+            interpret_syscall_arg_field(context, index, field_value.into_string())
+        }
+        FieldType::AvcPermissions => interpret_avc_permissions_field(field_value.as_str()),
     }
 }
 
 // TODO: move this to a msg.rs inside interpret module
-fn interpret_msg_field(record_type: &str, field_value: String) -> FieldValue {
+fn interpret_msg_field(context: &InterpretContext, field_value: String) -> FieldValue {
     let Ok((_, key_value_list)) =
         // TODO: maybe we should refactor this so this doesn't use parser module functions...
-        all_consuming(parser::body::parse_key_value_list)
-            .parse(field_value.as_str())
+        parser::body::parse_msg_fields(field_value.as_str())
     else {
         return field_value.into();
     };
+    let context = InterpretContext::new(context.record_type, &key_value_list, context.options);
     let nested_field_value_map = key_value_list
         .into_iter()
         .map(|(key, value)| {
             // TODO: fields inside msg should be interpreted aswell?
-            let interpreted_value = interpret_field_value(record_type, &key, value);
-            (key, interpreted_value)
+            let interpreted_value = interpret_field_value(&context, &key, value);
+            (key.into_owned(), interpreted_value)
         })
-        .collect::<BTreeMap<String, FieldValue>>();
+        .collect::<Fields<FieldValue>>();
 
     nested_field_value_map.into()
 }
 
-fn interpret_escaped_field(field_value: String) -> FieldValue {
-    // TODO handle `au_unescape` correctly (for example, see the parenthesis and (null))
-    // https://github.com/linux-audit/audit-userspace/blob/747f67994b933fd70deed7d6f7cb0c40601f5bd1/auparse/interpret.c#L343
-    let hex_decoded =
-        hex::decode(&field_value).map(|bytes| String::from_utf8_lossy(&bytes).to_string());
-    hex_decoded.unwrap_or(field_value).into()
+/// Enrichment values are already interpreted by auditd, so they are left as-is,
+/// except for braced groups of key-value pairs (such as `SADDR={ fam=local path=/tmp/foo }`),
+/// which are returned as a map.
+fn interpret_enrichment_value(value: Cow<'_, str>) -> FieldValue {
+    let Ok((_, fields)) = parser::body::parse_braced_fields(&value) else {
+        return value.into();
+    };
+
+    fields
+        .into_iter()
+        .map(|(key, value)| (key.into_owned(), value.into_string().into()))
+        .collect::<Fields<FieldValue>>()
+        .into()
 }
 
-fn interpret_uid_field(field_value: String) -> FieldValue {
-    let Ok(uid) = field_value.parse::<i64>() else {
+// Based on `print_escaped` and `au_unescape` from auparse
+// https://github.com/linux-audit/audit-userspace/blob/747f67994b933fd70deed7d6f7cb0c40601f5bd1/auparse/interpret.c#L343
+fn interpret_escaped_field(field_value: RawValue<'_>) -> FieldValue {
+    let field_value = match field_value {
+        // Quoted values are logged verbatim
+        RawValue::Quoted(value) => return value.into(),
+        // Values such as `(null)` or `(none)` are not encoded
+        RawValue::Unquoted(value) if value.starts_with('(') => return value.into(),
+        RawValue::Unquoted(value) => value,
+    };
+
+    let Ok(bytes) = hex::decode(field_value.as_bytes()) else {
         return field_value.into();
     };
 
-    let uid = uid::resolve_uid(uid);
+    // Decoded values are not guaranteed to be valid UTF-8, so keep the bytes in that case
+    match String::from_utf8(bytes) {
+        Ok(decoded) => decoded.into(),
+        Err(err) => FieldValue::Bytes(err.into_bytes()),
+    }
+}
+
+fn interpret_uid_field(uid_format: &UidFormat, is_gid: bool, field_value: String) -> FieldValue {
+    let Ok(uid_number) = field_value.parse::<i64>() else {
+        return field_value.into();
+    };
+
+    let names = match uid_format {
+        UidFormat::Default => None,
+        UidFormat::Numeric => return Number::SignedInteger(uid_number).into(),
+        UidFormat::Name { groups, .. } if is_gid => Some(groups),
+        UidFormat::Name { users, .. } => Some(users),
+    };
 
-    match uid {
-        Uid::Root => "root".to_string().into(),
-        Uid::User(uid) => Number::SignedInteger(uid).into(),
-        Uid::Unset => FieldValue::Null,
+    if let Some(name) = names
+        .zip(u32::try_from(uid_number).ok())
+        .and_then(|(names, uid)| names.get(&uid))
+    {
+        return name.clone().into();
+    }
+
+    let uid = uid::resolve_uid(uid_number);
+
+    match (uid, names) {
+        (Uid::Root, _) => "root".to_string().into(),
+        (Uid::User(uid), None) => Number::SignedInteger(uid).into(),
+        (Uid::Unset, None) => FieldValue::Null,
+        // Ref: https://github.com/linux-audit/audit-userspace/blob/747f67994b933fd70deed7d6f7cb0c40601f5bd1/auparse/interpret.c#L553
+        (Uid::User(uid), Some(_)) => format!("unknown({uid})").into(),
+        (Uid::Unset, Some(_)) => "unset".to_string().into(),
     }
 }
 
-fn interpret_exit_field(field_value: String) -> FieldValue {
+fn interpret_exit_field(success: Option<bool>, field_value: String) -> FieldValue {
     let Ok(exit_code) = field_value.parse::<i64>() else {
         return field_value.into();
     };
+
+    // Failed syscalls return the negated errno, which auparse shows as `EACCES(Permission denied)`
+    // Ref: https://github.com/linux-audit/audit-userspace/blob/747f67994b933fd70deed7d6f7cb0c40601f5bd1/auparse/interpret.c
+    if success == Some(false) && exit_code < 0 {
+        if let Ok(errno) = Errno::try_from(exit_code.unsigned_abs()) {
+            let mut map = BTreeMap::new();
+            map.insert("code".into(), Number::SignedInteger(exit_code).into());
+            map.insert("name".into(), FieldValue::Errno(errno));
+
+            return map.into();
+        }
+    }
+
     Number::SignedInteger(exit_code).into()
 }
 
-fn interpret_cap_bitmap_field(field_value: String) -> FieldValue {
+fn interpret_cap_bitmap_field(options: &InterpretOptions, field_value: String) -> FieldValue {
     // Capabilities are encoded as a 64-bit hexadecimal string
     let Ok(cap_bitmap) = u64::from_str_radix(&field_value, 16) else {
         return field_value.into();
@@ -156,10 +406,18 @@ fn interpret_cap_bitmap_field(field_value: String) -> FieldValue {
 
     let capabilities = capability::resolve_capability_bitmap(cap_bitmap);
 
-    capabilities.into()
+    if options.has_default_names() {
+        return capabilities.into();
+    }
+
+    capabilities
+        .iter()
+        .map(|capability| options.style_name(capability, "CAP_"))
+        .collect::<Vec<_>>()
+        .into()
 }
 
-fn interpret_socket_addr_field(field_value: String) -> FieldValue {
+fn interpret_socket_addr_field(options: &InterpretOptions, field_value: String) -> FieldValue {
     let Ok(byte_vec) = hex::decode(&field_value) else {
         return field_value.into();
     };
@@ -169,32 +427,10 @@ fn interpret_socket_addr_field(field_value: String) -> FieldValue {
         return field_value.into();
     };
 
-    let mut map = BTreeMap::new();
-
-    map.insert("family".into(), socket_address.family().into());
-    match socket_address {
-        SocketAddr::Unix(unix_address) => {
-            map.insert("path".into(), unix_address.path.into());
-        }
-        SocketAddr::Inet(inet_address) => {
-            map.insert("address".into(), inet_address.to_string().into());
-        }
-        SocketAddr::Inet6(inet6_address) => {
-            map.insert("address".into(), inet6_address.to_string().into());
-        }
-        SocketAddr::Netlink(netlink_address) => {
-            map.insert(
-                "port_id".into(),
-                Number::from(u64::from(netlink_address.port_id)).into(),
-            );
-            map.insert(
-                "multicast_groups_mask".into(),
-                Number::from(u64::from(netlink_address.multicast_groups_mask)).into(),
-            );
-        }
+    FieldValue::SocketAddr {
+        family: options.style_name(socket_address.family(), "AF_"),
+        address: socket_address,
     }
-
-    map.into()
 }
 
 fn interpret_perm_field(field_value: String) -> FieldValue {
@@ -210,14 +446,20 @@ fn interpret_perm_field(field_value: String) -> FieldValue {
 }
 
 fn interpret_result_field(field_value: &str) -> FieldValue {
-    result::resolve_result(field_value).to_string().into()
+    FieldValue::Result(result::resolve_result(field_value))
 }
 
-fn interpret_proctitle_field(field_value: String) -> FieldValue {
-    let Ok(bytes) = hex::decode(&field_value) else {
+fn interpret_proctitle_field(field_value: RawValue<'_>) -> FieldValue {
+    let field_value = match field_value {
+        // Quoted proctitles do not contain arguments separated by `\x00`
+        RawValue::Quoted(value) => return vec![value.into_owned()].into(),
+        RawValue::Unquoted(value) => value,
+    };
+
+    let Ok(bytes) = hex::decode(field_value.as_bytes()) else {
         // If the field is not encoded as a hexstring, we assume that
         // it does not contain arguments separated by `\x00` and we return the field as is
-        return field_value.into();
+        return vec![field_value.into_owned()].into();
     };
 
     proctitle::parse_proctitle(&bytes).into()
@@ -229,27 +471,7 @@ fn interpret_mode_field(field_value: String) -> FieldValue {
         return field_value.into();
     };
 
-    let mut map = BTreeMap::new();
-
-    map.insert("file_type".into(), mode.file_type.to_string().into());
-    map.insert(
-        "attributes".into(),
-        utils::into_string_array_to_field_value(&mode.attributes),
-    );
-    map.insert(
-        "user".into(),
-        utils::into_string_array_to_field_value(&mode.user),
-    );
-    map.insert(
-        "group".into(),
-        utils::into_string_array_to_field_value(&mode.group),
-    );
-    map.insert(
-        "other".into(),
-        utils::into_string_array_to_field_value(&mode.other),
-    );
-
-    map.into()
+    FieldValue::Mode(mode)
 }
 
 fn interpret_signal_field(field_value: String) -> FieldValue {
@@ -261,7 +483,7 @@ fn interpret_signal_field(field_value: String) -> FieldValue {
         return Number::UnsignedInteger(signal_number).into();
     };
 
-    signal.to_string().into()
+    FieldValue::Signal(signal)
 }
 
 fn interpret_list_field(field_value: String) -> FieldValue {
@@ -273,7 +495,7 @@ fn interpret_list_field(field_value: String) -> FieldValue {
         return Number::UnsignedInteger(audit_flag_number).into();
     };
 
-    audit_flag.to_string().into()
+    FieldValue::AuditFlag(audit_flag)
 }
 
 fn interpret_success_field(field_value: String) -> FieldValue {
@@ -293,7 +515,7 @@ fn interpret_errno_field(field_value: String) -> FieldValue {
         return Number::UnsignedInteger(errno_number).into();
     };
 
-    errno.to_string().into()
+    FieldValue::Errno(errno)
 }
 
 fn interpret_mac_label_field(field_value: String) -> FieldValue {
@@ -301,27 +523,7 @@ fn interpret_mac_label_field(field_value: String) -> FieldValue {
         return field_value.into();
     };
 
-    let mut map = BTreeMap::new();
-
-    map.insert("module".into(), mac_label.module().into());
-    match mac_label {
-        MacLabel::SELinux(context) => {
-            map.insert("user".into(), context.user.into());
-            map.insert("role".into(), context.role.into());
-            map.insert("type".into(), context.r#type.into());
-
-            if let Some(level) = context.level {
-                let mut level_map = BTreeMap::new();
-                level_map.insert("sensitivity".into(), level.sensitivity.into());
-                if let Some(category) = level.category {
-                    level_map.insert("category".into(), category.into());
-                }
-                map.insert("level".into(), level_map.into());
-            }
-
-            map.into()
-        }
-    }
+    FieldValue::MacLabel(mac_label)
 }
 
 fn interpret_pam_grantors_field(field_value: &str) -> FieldValue {
@@ -330,19 +532,73 @@ fn interpret_pam_grantors_field(field_value: &str) -> FieldValue {
     utils::into_string_array_to_field_value(&grantors)
 }
 
-fn interpret_arch_field(field_value: &str) -> FieldValue {
+fn parse_arch(field_value: &str) -> Option<AuditArch> {
     // Arch is a hex-encoded int. We assume that it is u32
     // Logging example https://github.com/torvalds/linux/blob/561c80369df0733ba0574882a1635287b20f9de2/kernel/auditsc.c#L1689
     // audit_context field: https://github.com/torvalds/linux/blob/c17b750b3ad9f45f2b6f7e6f7f4679844244f0b9/kernel/audit.h#L141
-    let Ok(arch) = u32::from_str_radix(field_value, 16) else {
+    let arch = u32::from_str_radix(field_value, 16).ok()?;
+
+    AuditArch::try_from(arch).ok()
+}
+
+fn interpret_arch_field(field_value: &str) -> FieldValue {
+    let Some(audit_arch) = parse_arch(field_value) else {
         return field_value.into();
     };
 
-    let Ok(audit_arch) = AuditArch::try_from(arch) else {
+    FieldValue::Arch(audit_arch)
+}
+
+fn interpret_syscall_field(arch: Option<AuditArch>, field_value: String) -> FieldValue {
+    let Ok(syscall_number) = field_value.parse::<u32>() else {
         return field_value.into();
     };
 
-    audit_arch.to_string().into()
+    // Syscall numbers depend on the architecture, so they can only be resolved
+    // when the record has a known `arch` field
+    let Some(syscall) = arch.and_then(|arch| syscall::resolve_syscall(arch, syscall_number)) else {
+        return Number::UnsignedInteger(u64::from(syscall_number)).into();
+    };
+
+    syscall.to_string().into()
+}
+
+fn interpret_syscall_arg_field(
+    context: &InterpretContext,
+    index: usize,
+    field_value: String,
+) -> FieldValue {
+    // Arguments are only meaningful along with the syscall, which also needs the arch
+    // (e.g. EXECVE records have `a0`..`aN` fields that are not syscall arguments)
+    let (Some(arch), Some(syscall)) = (context.arch(), context.syscall()) else {
+        return field_value.into();
+    };
+
+    // Syscall arguments are logged as hexadecimal numbers
+    let Ok(arg) = u64::from_str_radix(&field_value, 16) else {
+        return field_value.into();
+    };
+
+    let Some(syscall_arg) = syscall_arg::resolve_syscall_arg(arch, syscall, index, arg) else {
+        return field_value.into();
+    };
+
+    match syscall_arg {
+        // Socket families are the only constants with styled names
+        SyscallArg::Constant(name) if name.starts_with("AF_") => {
+            context.options.style_name(name, "AF_").into()
+        }
+        SyscallArg::Constant(name) => name.to_string().into(),
+        SyscallArg::Flags(flags) => flags.into(),
+        SyscallArg::Mode(mode) => FieldValue::Mode(mode),
+        SyscallArg::Signal(signal) => FieldValue::Signal(signal),
+    }
+}
+
+fn interpret_avc_permissions_field(field_value: &str) -> FieldValue {
+    let permissions = avc::parse_permissions(field_value);
+
+    utils::into_string_array_to_field_value(&permissions)
 }
 
 #[cfg(test)]
@@ -351,22 +607,202 @@ mod tests {
     use rstest::rstest;
 
     use super::*;
+    use crate::ParseOptions;
 
     // TODO: add tests for interpret_msg_field
     //
 
     #[rstest]
-    #[case::null("?", FieldValue::Null)]
-    fn test_interpret_field_value(#[case] field_value: String, #[case] expected: FieldValue) {
-        let result = interpret_field_value("test_type", "test_field_name", field_value);
+    #[case::null("?".into(), FieldValue::Null)]
+    #[case::quoted_null(RawValue::Quoted("?".into()), FieldValue::Null)]
+    fn test_interpret_field_value(#[case] field_value: RawValue, #[case] expected: FieldValue) {
+        let record_type = RecordType::Other("test_type".into());
+        let fields = Fields::new();
+        let options = InterpretOptions::default();
+        let context = InterpretContext::new(&record_type, &fields, &options);
+        let result = interpret_field_value(&context, "test_field_name", field_value);
+        assert_eq!(result, expected);
+    }
+
+    #[rstest]
+    #[case::x86_64(Fields::from([("arch", "c000003e".into()), ("syscall", "49".into())]), "bind".into())]
+    #[case::aarch64(Fields::from([("arch", "c00000b7".into()), ("syscall", "200".into())]), "bind".into())]
+    #[case::unsupported_arch(
+        Fields::from([("arch", "2b".into()), ("syscall", "49".into())]),
+        Number::UnsignedInteger(49).into()
+    )]
+    #[case::missing_arch(Fields::from([("syscall", "49".into())]), Number::UnsignedInteger(49).into())]
+    #[case::not_a_number(
+        Fields::from([("arch", "c000003e".into()), ("syscall", "foo".into())]),
+        "foo".into()
+    )]
+    fn test_interpret_syscall_with_sibling_arch(
+        #[case] fields: RawFields<'_>,
+        #[case] expected: FieldValue,
+    ) {
+        let record_type = RecordType::Syscall;
+        let options = InterpretOptions::default();
+        let context = InterpretContext::new(&record_type, &fields, &options);
+        let syscall = fields.get("syscall").unwrap().clone();
+        let result = interpret_field_value(&context, "syscall", syscall);
         assert_eq!(result, expected);
     }
 
     #[rstest]
-    #[case::hex_encoded("666f6f","foo".into())]
-    #[case::not_encoded_fallbacks_to_input("foo", "foo".into())]
-    #[case::hex_encoded_with_trailing_data_fallbacks_to_input("666f6fbar", "666f6fbar".into())]
-    fn test_interpret_escaped_field(#[case] input: String, #[case] expected: FieldValue) {
+    #[case::socket_family("socket", "a0", "11", "AF_PACKET".into())]
+    #[case::socket_type("socket", "a1", "3", vec!["SOCK_RAW".into()].into())]
+    #[case::ptrace("ptrace", "a0", "10", "PTRACE_ATTACH".into())]
+    #[case::kill("kill", "a1", "9", FieldValue::Signal(Signal::SIGKILL))]
+    #[case::open_flags("open", "a1", "241", vec!["O_WRONLY".into(), "O_CREAT".into(), "O_TRUNC".into()].into())]
+    #[case::pointer("open", "a0", "5555665d91b0", "5555665d91b0".into())]
+    #[case::not_hex("kill", "a1", "foo", "foo".into())]
+    fn test_interpret_syscall_arg_field(
+        #[case] syscall: &str,
+        #[case] field_name: &str,
+        #[case] field_value: &str,
+        #[case] expected: FieldValue,
+    ) {
+        let syscall_number = match syscall {
+            "socket" => "41",
+            "ptrace" => "101",
+            "kill" => "62",
+            "open" => "2",
+            _ => unreachable!(),
+        };
+        let fields = Fields::from([
+            ("arch", "c000003e".into()),
+            ("syscall", syscall_number.into()),
+            (field_name, field_value.into()),
+        ]);
+        let record_type = RecordType::Syscall;
+        let options = InterpretOptions::default();
+        let context = InterpretContext::new(&record_type, &fields, &options);
+        let result = interpret_field_value(&context, field_name, field_value.into());
+        assert_eq!(result, expected);
+    }
+
+    #[test]
+    fn test_interpret_syscall_arg_field_without_syscall() {
+        // Arguments can not be resolved without the syscall they were passed to
+        let fields = Fields::from([("arch", "c000003e".into()), ("a0", "1f".into())]);
+        let record_type = RecordType::Syscall;
+        let options = InterpretOptions::default();
+        let context = InterpretContext::new(&record_type, &fields, &options);
+        let result = interpret_field_value(&context, "a0", "1f".into());
+        assert_eq!(result, "1f".into());
+    }
+
+    fn parse_with_raw_values(line: &str) -> AuditdRecord {
+        let options = ParseOptions {
+            interpret: InterpretOptions::new().keep_raw_values(true),
+            ..Default::default()
+        };
+        AuditdRecord::parse_with(line, &options).unwrap()
+    }
+
+    #[test]
+    fn test_raw_fields_keep_logged_values() {
+        let line =
+            "type=SOCKADDR msg=audit(1725039526.208:52): saddr=02000050A9FEA9FE uid=0 comm=\"cat\"";
+        let record = parse_with_raw_values(line);
+
+        let expected = Fields::from([
+            ("saddr", "02000050A9FEA9FE".to_string()),
+            ("uid", "0".to_string()),
+            ("comm", "cat".to_string()),
+        ]);
+        assert_eq!(record.raw_fields, expected);
+        assert_eq!(record.fields.get("uid"), Some(&"root".into()));
+    }
+
+    #[test]
+    fn test_raw_fields_are_not_kept_by_default() {
+        let line = "type=SYSCALL msg=audit(1725039526.208:52): uid=0 comm=\"cat\"";
+        let record = line.parse::<AuditdRecord>().unwrap();
+
+        assert!(record.raw_fields.is_empty());
+        assert_eq!(record.fields.get("uid"), Some(&"root".into()));
+    }
+
+    #[test]
+    fn test_valid_lines_keep_escape_code_points() {
+        let line =
+            "type=SYSCALL msg=audit(1725039526.208:52): comm=\"a\u{10FFFF}b\" exe=61F48FBFBF62";
+        let expected = FieldValue::from("a\u{10FFFF}b");
+
+        let record = line.parse::<AuditdRecord>().unwrap();
+        assert_eq!(record.fields.get("comm"), Some(&expected));
+        assert_eq!(record.fields.get("exe"), Some(&expected));
+
+        let record = AuditdRecord::parse_bytes(line.as_bytes()).unwrap();
+        assert_eq!(record.fields.get("comm"), Some(&expected));
+        assert_eq!(record.fields.get("exe"), Some(&expected));
+    }
+
+    #[test]
+    fn test_parse_with_interpret_options() {
+        let line = "type=SYSCALL msg=audit(1725039526.208:52): uid=0 gid=0 comm=\"cat\"";
+        let options = ParseOptions {
+            interpret: InterpretOptions::new()
+                .disable(FieldType::Gid)
+                .uid_format(UidFormat::Numeric),
+            ..Default::default()
+        };
+        let record = AuditdRecord::parse_with(line, &options).unwrap();
+
+        assert_eq!(
+            record.fields.get("uid"),
+            Some(&Number::SignedInteger(0).into())
+        );
+        assert_eq!(record.fields.get("gid"), Some(&"0".into()));
+    }
+
+    #[test]
+    fn test_raw_fields_keep_execve_arguments() {
+        let line = "type=EXECVE msg=audit(1725039526.208:52): argc=2 a0=\"ls\" a1=\"-l\"";
+        let record = parse_with_raw_values(line);
+
+        assert_eq!(record.raw_fields.get("a1"), Some(&"-l".to_string()));
+        assert!(!record.fields.contains_key("a1"));
+        assert!(!record.raw_fields.contains_key("argv"));
+    }
+
+    #[test]
+    fn test_execve_arguments_with_gaps_are_not_reassembled() {
+        let line = "type=EXECVE msg=audit(1725039526.208:52): argc=3 a0=\"ls\" a2=2D6C a3=66FF6F";
+        let record = line.parse::<AuditdRecord>().unwrap();
+
+        assert!(!record.fields.contains_key("argv"));
+        assert_eq!(record.fields.get("a0"), Some(&"ls".into()));
+        assert_eq!(record.fields.get("a2"), Some(&"-l".into()));
+        assert_eq!(
+            record.fields.get("a3"),
+            Some(&FieldValue::Bytes(vec![0x66, 0xff, 0x6f]))
+        );
+    }
+
+    #[rstest]
+    #[case::regular("root", "root".into())]
+    #[case::multi_word("unknown family(0)", "unknown family(0)".into())]
+    #[case::braced("{ fam=local path=/tmp/{foo} }", btreemap!{
+        "fam".into() => "local".into(),
+        "path".into() => "/tmp/{foo}".into(),
+    }.into())]
+    fn test_interpret_enrichment_value(#[case] input: &str, #[case] expected: FieldValue) {
+        let result = interpret_enrichment_value(Cow::Borrowed(input));
+        assert_eq!(result, expected);
+    }
+
+    #[rstest]
+    #[case::hex_encoded("666f6f".into(), "foo".into())]
+    #[case::not_encoded_fallbacks_to_input("foo".into(), "foo".into())]
+    #[case::hex_encoded_with_trailing_data_fallbacks_to_input("666f6fbar".into(), "666f6fbar".into())]
+    #[case::quoted_hex_is_not_decoded(RawValue::Quoted("cafe".into()), "cafe".into())]
+    #[case::quoted_empty(RawValue::Quoted("".into()), "".into())]
+    #[case::parenthesized("(null)".into(), "(null)".into())]
+    #[case::parenthesized_hex("(cafe)".into(), "(cafe)".into())]
+    #[case::non_utf8("66ff6f".into(), FieldValue::Bytes(vec![0x66, 0xff, 0x6f]))]
+    fn test_interpret_escaped_field(#[case] input: RawValue, #[case] expected: FieldValue) {
         let result = interpret_escaped_field(input);
         assert_eq!(result, expected);
     }
@@ -379,54 +815,174 @@ mod tests {
     #[case::negative_integer("-123", Number::SignedInteger(-123).into())]
     #[case::not_integer_fallbacks_to_input("foo", "foo".into())]
     fn test_interpret_uid_field(#[case] input: String, #[case] expected: FieldValue) {
-        let result = interpret_uid_field(input);
+        let result = interpret_uid_field(&UidFormat::Default, false, input);
         assert_eq!(result, expected);
     }
 
+    #[rstest]
+    #[case::numeric_root(UidFormat::Numeric, false, "0", Number::SignedInteger(0).into())]
+    #[case::numeric_unset(UidFormat::Numeric, false, "4294967295", Number::SignedInteger(4_294_967_295).into())]
+    #[case::name_user(names(), false, "1000", "jorge".into())]
+    #[case::name_group(names(), true, "1000", "wheel".into())]
+    #[case::name_root(names(), false, "0", "root".into())]
+    #[case::name_unset(names(), false, "-1", "unset".into())]
+    #[case::name_unknown(names(), false, "1001", "unknown(1001)".into())]
+    #[case::name_not_integer_fallbacks_to_input(names(), false, "foo", "foo".into())]
+    fn test_interpret_uid_field_with_format(
+        #[case] uid_format: UidFormat,
+        #[case] is_gid: bool,
+        #[case] input: String,
+        #[case] expected: FieldValue,
+    ) {
+        let result = interpret_uid_field(&uid_format, is_gid, input);
+        assert_eq!(result, expected);
+    }
+
+    fn names() -> UidFormat {
+        UidFormat::Name {
+            users: BTreeMap::from([(1000, "jorge".to_string())]),
+            groups: BTreeMap::from([(1000, "wheel".to_string())]),
+        }
+    }
+
     #[rstest]
     #[case::zero("0", Number::SignedInteger(0).into())]
     #[case::positive_integer("123", Number::SignedInteger(123).into())]
     #[case::negative_integer("-123", Number::SignedInteger(-123).into())]
     #[case::not_integer_fallbacks_to_input("foo","foo".into())]
     fn test_interpret_exit_field(#[case] input: String, #[case] expected: FieldValue) {
-        let result = interpret_exit_field(input);
+        let result = interpret_exit_field(None, input);
+        assert_eq!(result, expected);
+    }
+
+    #[rstest]
+    #[case::failed_with_errno(Some(false), "-13", btreemap!{
+            "code".into() => Number::SignedInteger(-13).into(),
+            "name".into() => FieldValue::Errno(Errno::EACCES),
+        }.into()
+    )]
+    #[case::failed_with_unknown_errno(Some(false), "-9999", Number::SignedInteger(-9999).into())]
+    #[case::failed_with_positive_exit(Some(false), "13", Number::SignedInteger(13).into())]
+    #[case::succeeded_with_negative_exit(Some(true), "-13", Number::SignedInteger(-13).into())]
+    #[case::succeeded(Some(true), "3", Number::SignedInteger(3).into())]
+    fn test_interpret_exit_field_with_success(
+        #[case] success: Option<bool>,
+        #[case] input: String,
+        #[case] expected: FieldValue,
+    ) {
+        let result = interpret_exit_field(success, input);
+        assert_eq!(result, expected);
+    }
+
+    #[rstest]
+    #[case::default(InterpretOptions::default(), vec!["CHOWN".into(), "KILL".into()].into())]
+    #[case::kernel(
+        InterpretOptions::new().name_style(NameStyle::Kernel),
+        vec!["CAP_CHOWN".into(), "CAP_KILL".into()].into()
+    )]
+    #[case::kernel_lowercase(
+        InterpretOptions::new().name_style(NameStyle::Kernel).lowercase_names(true),
+        vec!["cap_chown".into(), "cap_kill".into()].into()
+    )]
+    fn test_interpret_cap_bitmap_field_with_name_style(
+        #[case] options: InterpretOptions,
+        #[case] expected: FieldValue,
+    ) {
+        let result = interpret_cap_bitmap_field(&options, "21".into());
+        assert_eq!(result, expected);
+    }
+
+    #[test]
+    fn test_interpret_socket_addr_field_with_name_style() {
+        let options = InterpretOptions::new().name_style(NameStyle::Unprefixed);
+        let result = interpret_socket_addr_field(&options, "02000050A9FEA9FE".into());
+        let expected = FieldValue::SocketAddr {
+            address: SocketAddr::Inet("169.254.169.254:80".parse().unwrap()),
+            family: "INET".into(),
+        };
         assert_eq!(result, expected);
     }
 
+    #[cfg(feature = "serde")]
+    #[test]
+    fn test_serialize_socket_addr_field_with_name_style() {
+        let options = InterpretOptions::new().name_style(NameStyle::Unprefixed);
+        let result = interpret_socket_addr_field(&options, "02000050A9FEA9FE".into());
+        insta::assert_json_snapshot!(result, @r#"
+        {
+          "address": "169.254.169.254:80",
+          "family": "INET"
+        }
+        "#);
+    }
+
+    #[rstest]
+    #[case::avc_prefix(
+        "type=AVC msg=audit(1725039526.208:52): avc:  denied  { read write } for  pid=1",
+        vec!["read".into(), "write".into()].into()
+    )]
+    #[case::avc_without_prefix(
+        "type=AVC msg=audit(1725039526.208:52): pid=1 permissions=foo",
+        "foo".into()
+    )]
+    #[case::non_avc(
+        "type=USER_ACCT msg=audit(1725039526.208:52): pid=1 permissions=foo",
+        "foo".into()
+    )]
+    fn test_interpret_avc_permissions_only_from_prefix(
+        #[case] line: &str,
+        #[case] expected: FieldValue,
+    ) {
+        let record = line.parse::<AuditdRecord>().unwrap();
+        assert_eq!(record.fields.get("permissions"), Some(&expected));
+    }
+
+    #[rstest]
+    #[case::uid("uid", "0")]
+    #[case::msg("msg", "op=login")]
+    #[case::syscall_arg("a1", "9")]
+    fn test_interpret_disabled_field_type(#[case] field_name: &str, #[case] field_value: &str) {
+        let options = InterpretOptions::new()
+            .disable(FieldType::Uid)
+            .disable(FieldType::Msg)
+            .disable(FieldType::SyscallArg(0));
+        let fields = Fields::from([
+            ("arch", "c000003e".into()),
+            ("syscall", "62".into()),
+            (field_name, field_value.into()),
+        ]);
+        let record_type = RecordType::Syscall;
+        let context = InterpretContext::new(&record_type, &fields, &options);
+        let result = interpret_field_value(&context, field_name, field_value.into());
+        assert_eq!(result, field_value.into());
+    }
+
     // TODO: add tests for interpret_socket_addr_field.
     // Use https://docs.rs/maplit/latest/maplit/macro.btreemap.html as a test dependency
     // to create BTreeMaps in the test cases
     #[rstest]
     #[case::af_unix("01002F7661722F72756E2F6E7363642F736F636B6574",
-        btreemap!{
-            "family".into() => "AF_UNIX".into(),
-            "path".into() => "/var/run/nscd/socket".into(),
-        }.into()
+        SocketAddr::Unix(SocketAddrLocal {
+            path: "/var/run/nscd/socket".into(),
+        }).into()
     )]
     #[case::af_inet("02000050A9FEA9FE",
-        btreemap!{
-            "family".into() => "AF_INET".into(),
-            "address".into() => "169.254.169.254:80".into(),
-        }.into()
+        SocketAddr::Inet("169.254.169.254:80".parse().unwrap()).into()
     )]
     #[case::af_inet6("0A0000160000000020010DC8E0040001000000000000F00A00000000",
-        btreemap!{
-            "family".into() => "AF_INET6".into(),
-            "address".into() => "[2001:dc8:e004:1::f00a]:22".into(),
-        }.into()
+        SocketAddr::Inet6("[2001:dc8:e004:1::f00a]:22".parse().unwrap()).into()
     )]
     #[case::af_netlink("100000001000000001000000",
-        btreemap!{
-            "family".into() => "AF_NETLINK".into(),
-            "port_id".into() => Number::UnsignedInteger(16).into(),
-            "multicast_groups_mask".into() => Number::UnsignedInteger(1).into(),
-        }.into()
+        SocketAddr::Netlink(SocketAddrNetlink {
+            port_id: 16,
+            multicast_groups_mask: 1,
+        }).into()
     )]
     #[case::not_hexstring_fallbacks_to_input("foo", "foo".into())]
     #[case::incomplete_hexstring_fallbacks_to_input("012", "012".into())]
     #[case::parse_sockaddr_fail_fallbacks_to_input("FFFF0000", "FFFF0000".into())]
     fn test_interpret_socket_addr_field(#[case] input: String, #[case] expected: FieldValue) {
-        let result = interpret_socket_addr_field(input);
+        let result = interpret_socket_addr_field(&InterpretOptions::default(), input);
         assert_eq!(result, expected);
     }
 
@@ -450,25 +1006,27 @@ mod tests {
     }
 
     #[rstest]
-    #[case::failed("0", "failed".into())]
-    #[case::success("1", "success".into())]
-    #[case::unset("2", "unset".into())]
-    #[case::failed_string("failed", "failed".into())]
-    #[case::success_string("success", "success".into())]
-    #[case::foo("foo", "unset".into())]
+    #[case::failed("0", FieldValue::Result(AuditResult::Failed))]
+    #[case::success("1", FieldValue::Result(AuditResult::Success))]
+    #[case::unset("2", FieldValue::Result(AuditResult::Unset))]
+    #[case::failed_string("failed", FieldValue::Result(AuditResult::Failed))]
+    #[case::success_string("success", FieldValue::Result(AuditResult::Success))]
+    #[case::foo("foo", FieldValue::Result(AuditResult::Unset))]
     fn test_interpret_result_field(#[case] input: &str, #[case] expected: FieldValue) {
         let result = interpret_result_field(input);
         assert_eq!(result, expected);
     }
 
     #[rstest]
-    #[case::non_hexstring("foo", "foo".into())]
-    #[case::hexstring("666f6f", "foo".into())]
-    #[case::hexstring_with_args("666f6f00626172", "foo bar".into())]
-    #[case::hexstring_with_multiple_args("666f6f006261720062617a", "foo bar baz".into())]
-    #[case::non_utf8_hexstring("666f6f0062ff6172", "foo b�ar".into())]
-    #[case::empty("", "".into())]
-    fn test_interpret_proctitle_field(#[case] input: String, #[case] expected: FieldValue) {
+    #[case::non_hexstring("foo".into(), vec!["foo".into()].into())]
+    #[case::hexstring("666f6f".into(), vec!["foo".into()].into())]
+    #[case::hexstring_with_args("666f6f00626172".into(), vec!["foo".into(), "bar".into()].into())]
+    #[case::hexstring_with_multiple_args("666f6f006261720062617a".into(), vec!["foo".into(), "bar".into(), "baz".into()].into())]
+    #[case::hexstring_with_spaces_in_arg("726d00612062".into(), vec!["rm".into(), "a b".into()].into())]
+    #[case::non_utf8_hexstring("666f6f0062ff6172".into(), vec!["foo".into(), "b�ar".into()].into())]
+    #[case::quoted_hexstring(RawValue::Quoted("cafe".into()), vec!["cafe".into()].into())]
+    #[case::empty("".into(), vec![].into())]
+    fn test_interpret_proctitle_field(#[case] input: RawValue, #[case] expected: FieldValue) {
         let result = interpret_proctitle_field(input);
         assert_eq!(result, expected);
     }
@@ -477,31 +1035,31 @@ mod tests {
     //
     #[rstest]
     #[case("100644",
-        btreemap!{
-            "file_type".into() => "regular-file".into(),
-            "attributes".into() => vec![].into(),
-            "user".into() => vec!["read".into(), "write".into()].into(),
-            "group".into() => vec!["read".into()].into(),
-            "other".into() => vec!["read".into()].into(),
-        }.into()
+        FieldValue::Mode(Mode {
+            file_type: FileType::RegularFile,
+            attributes: vec![],
+            user: vec![Permission::Read, Permission::Write],
+            group: vec![Permission::Read],
+            other: vec![Permission::Read],
+        })
     )]
     #[case("7777",
-        btreemap!{
-            "file_type".into() => "unknown".into(),
-            "attributes".into() => vec!["sticky".into(), "setgid".into(), "setuid".into()].into(),
-            "user".into() => vec!["read".into(), "write".into(), "exec".into()].into(),
-            "group".into() => vec!["read".into(), "write".into(), "exec".into()].into(),
-            "other".into() => vec!["read".into(), "write".into(), "exec".into()].into(),
-        }.into()
+        FieldValue::Mode(Mode {
+            file_type: FileType::Unknown,
+            attributes: vec![Attribute::Sticky, Attribute::Setgid, Attribute::Setuid],
+            user: vec![Permission::Read, Permission::Write, Permission::Exec],
+            group: vec![Permission::Read, Permission::Write, Permission::Exec],
+            other: vec![Permission::Read, Permission::Write, Permission::Exec],
+        })
     )]
     #[case("100000",
-        btreemap!{
-            "file_type".into() => "regular-file".into(),
-            "attributes".into() => vec![].into(),
-            "user".into() => vec![].into(),
-            "group".into() => vec![].into(),
-            "other".into() => vec![].into(),
-        }.into()
+        FieldValue::Mode(Mode {
+            file_type: FileType::RegularFile,
+            attributes: vec![],
+            user: vec![],
+            group: vec![],
+            other: vec![],
+        })
     )]
     #[case::empty("", "".into())]
     #[case::foo("foo", "foo".into())]
@@ -514,8 +1072,8 @@ mod tests {
     #[case::foo("foo", "foo".into())]
     #[case::negative("-1", "-1".into())]
     #[case::zero("0", Number::UnsignedInteger(0).into())]
-    #[case::sighup("1", "SIGHUP".into())]
-    #[case::sigunused("32", "SIGUNUSED".into())]
+    #[case::sighup("1", FieldValue::Signal(Signal::SIGHUP))]
+    #[case::sigunused("32", FieldValue::Signal(Signal::SIGUNUSED))]
     #[case::unknown("33", Number::UnsignedInteger(33).into())]
     fn test_interpret_signal_field(#[case] input: String, #[case] expected: FieldValue) {
         let result = interpret_signal_field(input);
@@ -525,14 +1083,14 @@ mod tests {
     #[rstest]
     #[case::foo("foo", "foo".into())]
     #[case::negative("-1", "-1".into())]
-    #[case::user("0", "user".into())]
-    #[case::task("1", "task".into())]
-    #[case::entry("2", "entry".into())]
-    #[case::watch("3", "watch".into())]
-    #[case::exit("4", "exit".into())]
-    #[case::exclude("5", "exclude".into())]
-    #[case::filesystem("6", "filesystem".into())]
-    #[case::io_uring_exit("7", "io-uring-exit".into())]
+    #[case::user("0", FieldValue::AuditFlag(AuditFlag::User))]
+    #[case::task("1", FieldValue::AuditFlag(AuditFlag::Task))]
+    #[case::entry("2", FieldValue::AuditFlag(AuditFlag::Entry))]
+    #[case::watch("3", FieldValue::AuditFlag(AuditFlag::Watch))]
+    #[case::exit("4", FieldValue::AuditFlag(AuditFlag::Exit))]
+    #[case::exclude("5", FieldValue::AuditFlag(AuditFlag::Exclude))]
+    #[case::filesystem("6", FieldValue::AuditFlag(AuditFlag::Filesystem))]
+    #[case::io_uring_exit("7", FieldValue::AuditFlag(AuditFlag::IoUringExit))]
     #[case::unknown("8", Number::UnsignedInteger(8).into())]
     fn test_interpret_list_field(#[case] input: String, #[case] expected: FieldValue) {
         let result = interpret_list_field(input);
@@ -551,42 +1109,50 @@ mod tests {
     #[rstest]
     #[case::foo("foo", "foo".into())]
     #[case::zero("0", Number::UnsignedInteger(0).into())]
-    #[case::eperm("1", "EPERM".into())]
-    #[case::enoent("2", "ENOENT".into())]
-    #[case::enomem("12", "ENOMEM".into())]
+    #[case::eperm("1", FieldValue::Errno(Errno::EPERM))]
+    #[case::enoent("2", FieldValue::Errno(Errno::ENOENT))]
+    #[case::enomem("12", FieldValue::Errno(Errno::ENOMEM))]
     fn test_interpret_errno_field(#[case] input: String, #[case] expected: FieldValue) {
         let result = interpret_errno_field(input);
         assert_eq!(result, expected);
     }
 
     #[rstest]
-    #[case::selinux_minimal("user_u:role_r:type_t", btreemap!{
-            "module".into() => "SELinux".into(),
-            "user".into() => "user_u".into(),
-            "role".into() => "role_r".into(),
-            "type".into() => "type_t".into(),
-        }.into()
+    #[case::selinux_minimal("user_u:role_r:type_t",
+        FieldValue::MacLabel(MacLabel::SELinux(SELinuxContext {
+            user: "user_u".into(),
+            role: "role_r".into(),
+            r#type: "type_t".into(),
+            level: None,
+        }))
     )]
-    #[case::selinux_sensitivity("user_u:role_r:type_t:s0", btreemap!{
-            "module".into() => "SELinux".into(),
-            "user".into() => "user_u".into(),
-            "role".into() => "role_r".into(),
-            "type".into() => "type_t".into(),
-            "level".into() => btreemap!{
-                    "sensitivity".into() => "s0".into(),
-                }.into(),
-        }.into()
+    #[case::selinux_sensitivity("user_u:role_r:type_t:s0",
+        FieldValue::MacLabel(MacLabel::SELinux(SELinuxContext {
+            user: "user_u".into(),
+            role: "role_r".into(),
+            r#type: "type_t".into(),
+            level: Some(SELinuxLevel {
+                sensitivity: "s0".into(),
+                category: None,
+            }),
+        }))
     )]
-    #[case::selinux_sensitivity_and_category("user_u:role_r:type_t:s0:c1", btreemap!{
-            "module".into() => "SELinux".into(),
-            "user".into() => "user_u".into(),
-            "role".into() => "role_r".into(),
-            "type".into() => "type_t".into(),
-            "level".into() => btreemap!{
-                    "sensitivity".into() => "s0".into(),
-                    "category".into() => "c1".into(),
-                }.into(),
-        }.into()
+    #[case::selinux_sensitivity_and_category("user_u:role_r:type_t:s0:c1",
+        FieldValue::MacLabel(MacLabel::SELinux(SELinuxContext {
+            user: "user_u".into(),
+            role: "role_r".into(),
+            r#type: "type_t".into(),
+            level: Some(SELinuxLevel {
+                sensitivity: "s0".into(),
+                category: Some("c1".into()),
+            }),
+        }))
+    )]
+    #[case::apparmor("/usr/sbin/ntpd (enforce)",
+        FieldValue::MacLabel(MacLabel::AppArmor(AppArmorLabel {
+            profile: "/usr/sbin/ntpd".into(),
+            mode: AppArmorMode::Enforce,
+        }))
     )]
     #[case::not_a_mac_label("foo", "foo".into())]
     fn test_interpret_mac_label_field(#[case] input: String, #[case] expected: FieldValue) {
@@ -607,12 +1173,21 @@ mod tests {
     }
 
     #[rstest]
-    #[case::aarch64("c00000b7", "AArch64".into())]
-    #[case::x86_64("c000003e", "x86_64".into())]
+    #[case::aarch64("c00000b7", FieldValue::Arch(AuditArch::AARCH64))]
+    #[case::x86_64("c000003e", FieldValue::Arch(AuditArch::X86_64))]
     #[case::not_hex_encoded("foo", "foo".into())]
     #[case::unknown("9999", "9999".into())]
     fn test_interpret_arch_field(#[case] input: &str, #[case] expected: FieldValue) {
         let result = interpret_arch_field(input);
         assert_eq!(result, expected);
     }
+
+    #[rstest]
+    #[case::single_permission("read", vec!["read".into()].into())]
+    #[case::multiple_permissions("read write", vec!["read".into(), "write".into()].into())]
+    #[case::empty("", vec![].into())]
+    fn test_interpret_avc_permissions_field(#[case] input: &str, #[case] expected: FieldValue) {
+        let result = interpret_avc_permissions_field(input);
+        assert_eq!(result, expected);
+    }
 }
@@ -2,6 +2,8 @@
 
 use std::fmt::{self, Display, Formatter};
 
+use super::utils::impl_serialize_display;
+
 // ELF EM_ constants are extracted from https://github.com/torvalds/linux/blob/561c80369df0733ba0574882a1635287b20f9de2/include/uapi/linux/elf-em.h
 const EM_NONE: u32 = 0;
 const EM_M32: u32 = 1;
@@ -115,6 +117,7 @@ const AUDIT_ARCH_XTENSA: u32 = EM_XTENSA;
 const AUDIT_ARCH_LOONGARCH32: u32 = EM_LOONGARCH | AUDIT_ARCH_LE;
 const AUDIT_ARCH_LOONGARCH64: u32 = EM_LOONGARCH | AUDIT_ARCH_64BIT | AUDIT_ARCH_LE;
 
+/// Architecture of a syscall, from the `arch` field
 #[allow(clippy::upper_case_acronyms)]
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub enum AuditArch {
@@ -184,6 +187,8 @@ impl Display for AuditArch {
     }
 }
 
+impl_serialize_display!(AuditArch);
+
 impl TryFrom<u32> for AuditArch {
     type Error = ();
 
@@ -1,7 +1,10 @@
 use std::fmt::{self, Display, Formatter};
 
+use super::utils::impl_serialize_display;
+
 // Constants extracted from https://github.com/torvalds/linux/blob/4856ebd997159f198e3177e515bda01143727463/include/uapi/linux/audit.h#L171
-#[derive(Debug, PartialEq)]
+/// Audit rule list, from the `list` field of `CONFIG_CHANGE` records
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub enum AuditFlag {
     User,
     Task,
@@ -29,6 +32,8 @@ impl Display for AuditFlag {
     }
 }
 
+impl_serialize_display!(AuditFlag);
+
 pub fn resolve_audit_flag(audit_flag: u64) -> Option<AuditFlag> {
     let audit_flag = match audit_flag {
         0 => AuditFlag::User,
@@ -0,0 +1,23 @@
+// [SELinux Access Vector Cache (AVC)](https://selinuxproject.org/page/NB_AL) records
+// list the permissions that were granted or denied between curly braces, separated by spaces.
+// Ref: https://github.com/torvalds/linux/blob/4856ebd997159f198e3177e515bda01143727463/security/selinux/avc.c
+
+pub fn parse_permissions(permissions: &str) -> Vec<&str> {
+    permissions.split_whitespace().collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rstest::rstest;
+
+    #[rstest]
+    #[case("read", vec!["read"])]
+    #[case("read write", vec!["read", "write"])]
+    #[case("read  write ", vec!["read", "write"])]
+    #[case("", vec![])]
+    fn test_parse_permissions(#[case] input: &str, #[case] expected: Vec<&str>) {
+        let result = parse_permissions(input);
+        assert_eq!(result, expected);
+    }
+}
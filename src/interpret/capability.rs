@@ -3,9 +3,8 @@
 // Right now, capability format is the same as in kubernetes (CAP_XX in kernel-> XX in this parser)
 // Ref: https://kubernetes.io/docs/tasks/configure-pod-container/security-context/#set-capabilities-for-a-container
 pub const CAPABILITIES: [&str; 41] = [
-    // TODO: uppercase, lowercase (without CAP_) or should we output `CAP_CHOWN` as they are named in the kernel?
-    // or as they are output in `capsh --decode=0000001fffffffff``? (e.g. `cap_chown`)
-    // For example, in the socket family, we output `AF_INET` instead of `INET`
+    // Names can be output as they are named in the kernel (`CAP_CHOWN`) or as `capsh --decode` does
+    // (`cap_chown`) with the `NameStyle` of `InterpretOptions`
     "CHOWN",
     "DAC_OVERRIDE",
     "DAC_READ_SEARCH",
@@ -1,6 +1,9 @@
 use std::fmt::{self, Display, Formatter};
 
-#[derive(Debug, PartialEq)]
+use super::utils::impl_serialize_display;
+
+/// Error number, such as the exit code of failed syscalls
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
 #[allow(clippy::upper_case_acronyms)]
 pub enum Errno {
     EPERM,
@@ -146,6 +149,8 @@ impl Display for Errno {
     }
 }
 
+impl_serialize_display!(Errno);
+
 impl TryFrom<u64> for Errno {
     type Error = ();
 
@@ -0,0 +1,190 @@
+use std::collections::BTreeMap;
+
+use crate::parser::{RawFields, RawValue, unescape_bytes};
+
+/// Kind of an EXECVE argument field, which is logged by the kernel as `aN`,
+/// or split into `aN_len` and the chunks `aN[0]`, `aN[1]`, ... when the argument is long.
+///
+/// Ref: <https://github.com/torvalds/linux/blob/561c80369df0733ba0574882a1635287b20f9de2/kernel/auditsc.c>
+#[derive(Debug, PartialEq)]
+enum ArgField {
+    Value(usize),
+    Len(usize),
+    Chunk(usize, usize),
+}
+
+fn parse_arg_field(field_name: &str) -> Option<ArgField> {
+    let rest = field_name.strip_prefix('a')?;
+    let digits = rest
+        .find(|c: char| !c.is_ascii_digit())
+        .unwrap_or(rest.len());
+    let (index, suffix) = rest.split_at(digits);
+    let index = index.parse().ok()?;
+
+    if suffix.is_empty() {
+        return Some(ArgField::Value(index));
+    }
+
+    if suffix == "_len" {
+        return Some(ArgField::Len(index));
+    }
+
+    let chunk = suffix.strip_prefix('[')?.strip_suffix(']')?.parse().ok()?;
+    Some(ArgField::Chunk(index, chunk))
+}
+
+/// Returns whether the field is one of the arguments of an EXECVE record. Arguments that
+/// are not reassembled are interpreted as escaped fields, as they are logged the same way.
+pub fn is_arg_field(field_name: &str) -> bool {
+    parse_arg_field(field_name).is_some()
+}
+
+/// Arguments that need escaping are hex-encoded by the kernel, while the rest are quoted.
+/// Quoted arguments of lines that were not valid UTF-8 have their invalid bytes restored.
+fn decode_arg(value: &RawValue<'_>, invalid_utf8: bool) -> Vec<u8> {
+    match value {
+        RawValue::Quoted(value) => unescape_bytes(value)
+            .filter(|_| invalid_utf8)
+            .unwrap_or_else(|| value.as_bytes().to_vec()),
+        RawValue::Unquoted(value) => {
+            hex::decode(value.as_bytes()).unwrap_or_else(|_| value.as_bytes().to_vec())
+        }
+    }
+}
+
+/// Reassembles the arguments of an EXECVE record in order, decoding hex-encoded
+/// arguments and concatenating the chunks of long arguments.
+///
+/// Returns `None` if the arguments are not complete, so the fields are kept as they were
+/// logged: the record has no arguments, their indexes do not match its `argc` field
+/// (or have gaps if it is missing), an argument has neither a value nor chunks
+/// (e.g. only its `aN_len` field was logged) or it is not valid UTF-8.
+pub fn reassemble_argv(fields: &RawFields<'_>, invalid_utf8: bool) -> Option<Vec<String>> {
+    let mut values = BTreeMap::new();
+    let mut chunks = BTreeMap::<usize, BTreeMap<usize, &RawValue<'_>>>::new();
+    let mut indexes = Vec::new();
+
+    for (field_name, value) in fields.iter() {
+        let Some(arg_field) = parse_arg_field(field_name) else {
+            continue;
+        };
+
+        let index = match arg_field {
+            ArgField::Value(index) => {
+                values.insert(index, value);
+                index
+            }
+            ArgField::Chunk(index, chunk) => {
+                chunks.entry(index).or_default().insert(chunk, value);
+                index
+            }
+            ArgField::Len(index) => index,
+        };
+        indexes.push(index);
+    }
+
+    indexes.sort_unstable();
+    indexes.dedup();
+    let argc = match fields.get("argc") {
+        Some(argc) => argc.as_str().parse().ok()?,
+        None => indexes.len(),
+    };
+    if indexes.is_empty() || !indexes.iter().copied().eq(0..argc) {
+        return None;
+    }
+
+    indexes
+        .into_iter()
+        .map(|index| {
+            let bytes = match (values.get(&index), chunks.get(&index)) {
+                (Some(value), _) => decode_arg(value, invalid_utf8),
+                (None, Some(chunks)) => chunks
+                    .values()
+                    .flat_map(|chunk| decode_arg(chunk, invalid_utf8))
+                    .collect(),
+                (None, None) => return None,
+            };
+            String::from_utf8(bytes).ok()
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use rstest::rstest;
+
+    use super::*;
+    use crate::Fields;
+
+    #[rstest]
+    #[case::value("a0", Some(ArgField::Value(0)))]
+    #[case::multiple_digits("a12", Some(ArgField::Value(12)))]
+    #[case::len("a2_len", Some(ArgField::Len(2)))]
+    #[case::chunk("a2[10]", Some(ArgField::Chunk(2, 10)))]
+    #[case::argc("argc", None)]
+    #[case::no_index("a", None)]
+    #[case::unterminated_chunk("a2[1", None)]
+    #[case::other_suffix("a2_foo", None)]
+    fn test_parse_arg_field(#[case] input: &str, #[case] expected: Option<ArgField>) {
+        assert_eq!(parse_arg_field(input), expected);
+    }
+
+    #[rstest]
+    #[case::quoted(
+        Fields::from([("a0", RawValue::Quoted("ls".into())), ("a1", RawValue::Quoted("-l".into()))]),
+        Some(vec!["ls".into(), "-l".into()])
+    )]
+    #[case::hex_encoded(
+        Fields::from([("a0", RawValue::Quoted("echo".into())), ("a1", "666f6f20626172".into())]),
+        Some(vec!["echo".into(), "foo bar".into()])
+    )]
+    #[case::chunked(
+        Fields::from([
+            ("a0", RawValue::Quoted("echo".into())),
+            ("a1_len", "6".into()),
+            ("a1[0]", "666f6f".into()),
+            ("a1[1]", RawValue::Quoted("bar".into())),
+        ]),
+        Some(vec!["echo".into(), "foobar".into()])
+    )]
+    #[case::out_of_order(
+        Fields::from([("a1", RawValue::Quoted("-l".into())), ("a0", RawValue::Quoted("ls".into()))]),
+        Some(vec!["ls".into(), "-l".into()])
+    )]
+    #[case::missing_chunks(
+        Fields::from([("a0", RawValue::Quoted("ls".into())), ("a1_len", "6".into())]),
+        None
+    )]
+    #[case::no_args(Fields::new(), None)]
+    #[case::gap(
+        Fields::from([("a0", RawValue::Quoted("ls".into())), ("a2", RawValue::Quoted("-l".into()))]),
+        None
+    )]
+    #[case::missing_args(Fields::from([("a0", RawValue::Quoted("ls".into()))]), None)]
+    #[case::invalid_utf8(
+        Fields::from([("a0", RawValue::Quoted("echo".into())), ("a1", "66ff6f".into())]),
+        None
+    )]
+    fn test_reassemble_argv(#[case] fields: RawFields<'_>, #[case] expected: Option<Vec<String>>) {
+        // `argc` is not an argument, so it is skipped when reassembling
+        let mut fields = fields;
+        fields.insert("argc", "2".into());
+        assert_eq!(reassemble_argv(&fields, false), expected);
+    }
+
+    #[rstest]
+    #[case::contiguous(
+        Fields::from([("a0", RawValue::Quoted("ls".into())), ("a1", RawValue::Quoted("-l".into()))]),
+        Some(vec!["ls".into(), "-l".into()])
+    )]
+    #[case::gap(
+        Fields::from([("a0", RawValue::Quoted("ls".into())), ("a2", RawValue::Quoted("-l".into()))]),
+        None
+    )]
+    fn test_reassemble_argv_without_argc(
+        #[case] fields: RawFields<'_>,
+        #[case] expected: Option<Vec<String>>,
+    ) {
+        assert_eq!(reassemble_argv(&fields, false), expected);
+    }
+}
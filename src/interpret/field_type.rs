@@ -81,9 +81,14 @@ const RESULT_FIELD_NAMES: [&str; 2] = ["res", "result"];
 
 const SIGNAL_FIELD_NAMES: [&str; 2] = ["sig", "sigev_signo"];
 
+// Syscall arguments, in the order they are passed to the syscall
+const SYSCALL_ARG_FIELD_NAMES: [&str; 4] = ["a0", "a1", "a2", "a3"];
+
 const MAC_LABEL_FIELD_NAMES: [&str; 6] =
     ["subj", "obj", "scontext", "tcontext", "vm-ctx", "img-ctx"];
 
+/// Type of a field, resolved from its name, which determines how it is interpreted.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
 pub enum FieldType {
     Msg,
     Exit,
@@ -108,11 +113,16 @@ pub enum FieldType {
     PAMGrantors,
     /// Architecture of the machine
     Arch,
+    /// Permissions of `SELinux` AVC records
+    AvcPermissions,
+    /// Syscall number, which is resolved with the table of the record's architecture
+    Syscall,
+    /// Syscall argument (`a0`..`a3`), interpreted according to the record's syscall
+    SyscallArg(usize),
 }
 
 impl FieldType {
-    // TODO: interpret a[[:digit:]+]\[.*\]
-    pub fn resolve(field_name: &str) -> Option<Self> {
+    pub(crate) fn resolve(field_name: &str) -> Option<Self> {
         if field_name == "msg" {
             return Some(Self::Msg);
         }
@@ -153,6 +163,17 @@ impl FieldType {
             return Some(Self::Arch);
         }
 
+        if field_name == "syscall" {
+            return Some(Self::Syscall);
+        }
+
+        if let Some(index) = SYSCALL_ARG_FIELD_NAMES
+            .iter()
+            .position(|name| *name == field_name)
+        {
+            return Some(Self::SyscallArg(index));
+        }
+
         if ESCAPED_FIELD_NAMES.contains(&field_name) {
             return Some(Self::Escaped);
         }
@@ -1,9 +1,18 @@
-#[derive(Debug, PartialEq)]
+use std::fmt::{self, Display, Formatter};
+
+#[cfg(feature = "serde")]
+use serde::{Serialize, Serializer, ser::SerializeMap};
+
+use super::utils::impl_serialize_display;
+
+/// Security label of a Linux Security Module, such as an `SELinux` context
+#[derive(Debug, Clone, PartialEq, Eq)]
 pub enum MacLabel {
     SELinux(SELinuxContext),
+    AppArmor(AppArmorLabel),
 }
 
-#[derive(Debug, PartialEq)]
+#[derive(Debug, Clone, PartialEq, Eq)]
 pub struct SELinuxContext {
     pub user: String,
     pub role: String,
@@ -11,30 +20,136 @@ pub struct SELinuxContext {
     pub level: Option<SELinuxLevel>,
 }
 
-#[derive(Debug, PartialEq)]
+#[derive(Debug, Clone, PartialEq, Eq)]
 pub struct SELinuxLevel {
     pub sensitivity: String,
     pub category: Option<String>,
 }
 
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct AppArmorLabel {
+    pub profile: String,
+    pub mode: AppArmorMode,
+}
+
+// Modes extracted from https://github.com/torvalds/linux/blob/master/security/apparmor/policy.c
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum AppArmorMode {
+    Enforce,
+    Complain,
+    Kill,
+    Unconfined,
+    User,
+}
+
+impl Display for AppArmorMode {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        match self {
+            AppArmorMode::Enforce => write!(f, "enforce"),
+            AppArmorMode::Complain => write!(f, "complain"),
+            AppArmorMode::Kill => write!(f, "kill"),
+            AppArmorMode::Unconfined => write!(f, "unconfined"),
+            AppArmorMode::User => write!(f, "user"),
+        }
+    }
+}
+
+impl_serialize_display!(AppArmorMode);
+
 impl MacLabel {
+    #[must_use]
     pub fn module(&self) -> &str {
         match self {
             MacLabel::SELinux(_) => "SELinux",
+            MacLabel::AppArmor(_) => "AppArmor",
+        }
+    }
+}
+
+// Keys are sorted, the same as the map this was serialized as before being typed
+#[cfg(feature = "serde")]
+impl Serialize for MacLabel {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        let mut map = serializer.serialize_map(None)?;
+        match self {
+            MacLabel::SELinux(context) => {
+                if let Some(level) = &context.level {
+                    map.serialize_entry("level", level)?;
+                }
+                map.serialize_entry("module", self.module())?;
+                map.serialize_entry("role", &context.role)?;
+                map.serialize_entry("type", &context.r#type)?;
+                map.serialize_entry("user", &context.user)?;
+            }
+            MacLabel::AppArmor(label) => {
+                map.serialize_entry("mode", &label.mode)?;
+                map.serialize_entry("module", self.module())?;
+                map.serialize_entry("profile", &label.profile)?;
+            }
         }
+        map.end()
     }
 }
 
-// TODO: https://github.com/jorgehermo9/auditd-parser/issues/60
-// We will assume that the MAC is always SELinux.
-// Some distributions use other MAC such as AppArmor, but for now we will only interpret
-// SELinux ones.
+#[cfg(feature = "serde")]
+impl Serialize for SELinuxLevel {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        let mut map = serializer.serialize_map(None)?;
+        if let Some(category) = &self.category {
+            map.serialize_entry("category", category)?;
+        }
+        map.serialize_entry("sensitivity", &self.sensitivity)?;
+        map.end()
+    }
+}
+
+// Ref: https://github.com/jorgehermo9/auditd-parser/issues/60
 // Auparse does not interpret the MAC field and just outputs it as a string https://github.com/linux-audit/audit-userspace/blob/747f67994b933fd70deed7d6f7cb0c40601f5bd1/auparse/interpret.c#L3484
 // Ref: https://en.wikipedia.org/wiki/Mandatory_access_control
 pub fn resolve_mac_label(mac: &str) -> Option<MacLabel> {
+    // AppArmor labels are tried first, as they are more restrictive (they must end with the mode)
+    // and an AppArmor profile may contain colons, which would be wrongly parsed as a SELinux context.
+    if let Some(label) = parse_apparmor_label(mac) {
+        return Some(MacLabel::AppArmor(label));
+    }
+
     parse_selinux_context(mac).map(MacLabel::SELinux)
 }
 
+fn resolve_apparmor_mode(mode: &str) -> Option<AppArmorMode> {
+    let mode = match mode {
+        "enforce" => AppArmorMode::Enforce,
+        "complain" => AppArmorMode::Complain,
+        "kill" => AppArmorMode::Kill,
+        "unconfined" => AppArmorMode::Unconfined,
+        "user" => AppArmorMode::User,
+        _ => return None,
+    };
+    Some(mode)
+}
+
+pub fn parse_apparmor_label(label: &str) -> Option<AppArmorLabel> {
+    // Unconfined tasks are labeled just as `unconfined`, without mode.
+    if label == "unconfined" {
+        return Some(AppArmorLabel {
+            profile: label.to_string(),
+            mode: AppArmorMode::Unconfined,
+        });
+    }
+
+    // Some Ubuntu kernels log the label with a leading equal sign, such as `subj==/usr/sbin/ntpd (enforce)`
+    let label = label.strip_prefix('=').unwrap_or(label);
+    let (profile, mode) = label.strip_suffix(')')?.rsplit_once(" (")?;
+    if profile.is_empty() {
+        return None;
+    }
+
+    Some(AppArmorLabel {
+        profile: profile.to_string(),
+        mode: resolve_apparmor_mode(mode)?,
+    })
+}
+
 pub fn parse_selinux_context(context: &str) -> Option<SELinuxContext> {
     // Ref: https://docs.redhat.com/en/documentation/red_hat_enterprise_linux/7/html/selinux_users_and_administrators_guide/chap-security-enhanced_linux-selinux_contexts
     let parts: Vec<&str> = context.split(':').collect();
@@ -77,6 +192,14 @@ mod tests {
             category: Some("c1".to_string()),
         }),
     })))]
+    #[case::apparmor("/usr/sbin/ntpd (enforce)", Some(MacLabel::AppArmor(AppArmorLabel {
+        profile: "/usr/sbin/ntpd".to_string(),
+        mode: AppArmorMode::Enforce,
+    })))]
+    #[case::apparmor_with_colons("/usr/bin/a:b:c (complain)", Some(MacLabel::AppArmor(AppArmorLabel {
+        profile: "/usr/bin/a:b:c".to_string(),
+        mode: AppArmorMode::Complain,
+    })))]
     #[case::not_mac_label("foo", None)]
     fn test_resolve_mac_label(#[case] input: &str, #[case] expected: Option<MacLabel>) {
         let result = resolve_mac_label(input);
@@ -113,4 +236,34 @@ mod tests {
         let result = parse_selinux_context(input);
         assert_eq!(result, expected);
     }
+
+    #[rstest]
+    #[case::enforce("/usr/sbin/ntpd (enforce)", Some(AppArmorLabel {
+        profile: "/usr/sbin/ntpd".to_string(),
+        mode: AppArmorMode::Enforce,
+    }))]
+    #[case::complain("/usr/sbin/cupsd (complain)", Some(AppArmorLabel {
+        profile: "/usr/sbin/cupsd".to_string(),
+        mode: AppArmorMode::Complain,
+    }))]
+    #[case::kill("/usr/sbin/cupsd (kill)", Some(AppArmorLabel {
+        profile: "/usr/sbin/cupsd".to_string(),
+        mode: AppArmorMode::Kill,
+    }))]
+    #[case::unconfined("unconfined", Some(AppArmorLabel {
+        profile: "unconfined".to_string(),
+        mode: AppArmorMode::Unconfined,
+    }))]
+    #[case::leading_equal_sign("=/usr/bin/man//&man_groff (enforce)", Some(AppArmorLabel {
+        profile: "/usr/bin/man//&man_groff".to_string(),
+        mode: AppArmorMode::Enforce,
+    }))]
+    #[case::without_mode("/usr/sbin/ntpd", None)]
+    #[case::unknown_mode("/usr/sbin/ntpd (foo)", None)]
+    #[case::without_profile(" (enforce)", None)]
+    #[case::selinux_context("user_u:role_r:type_t:s0", None)]
+    fn test_parse_apparmor_label(#[case] input: &str, #[case] expected: Option<AppArmorLabel>) {
+        let result = parse_apparmor_label(input);
+        assert_eq!(result, expected);
+    }
 }
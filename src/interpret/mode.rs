@@ -1,5 +1,10 @@
 use std::fmt::{self, Display, Formatter};
 
+#[cfg(feature = "serde")]
+use serde::{Serialize, Serializer, ser::SerializeMap};
+
+use super::utils::impl_serialize_display;
+
 // Constants extracted from https://github.com/torvalds/linux/blob/5723cc3450bccf7f98f227b9723b5c9f6b3af1c5/include/uapi/linux/stat.h#L9
 const FILE_TYPE_MASK: u32 = 0o170_000;
 const ATTRIBUTES_MASK: u32 = 0o7_000;
@@ -24,7 +29,8 @@ const PERMISSION_READ_MASK: u32 = 0o4;
 const PERMISSION_WRITE_MASK: u32 = 0o2;
 const PERMISSION_EXEC_MASK: u32 = 0o1;
 
-#[derive(Debug, PartialEq)]
+/// File type and permissions of a file mode
+#[derive(Debug, Clone, PartialEq, Eq)]
 pub struct Mode {
     pub file_type: FileType,
     pub attributes: Vec<Attribute>,
@@ -33,7 +39,7 @@ pub struct Mode {
     pub other: Vec<Permission>,
 }
 
-#[derive(Debug, PartialEq)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub enum FileType {
     Socket,
     Symlink,
@@ -60,7 +66,7 @@ impl Display for FileType {
     }
 }
 
-#[derive(Debug, PartialEq)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub enum Permission {
     Read,
     Write,
@@ -78,7 +84,7 @@ impl Display for Permission {
     }
 }
 
-#[derive(Debug, PartialEq)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub enum Attribute {
     Sticky,
     Setgid,
@@ -95,22 +101,43 @@ impl Display for Attribute {
     }
 }
 
+impl_serialize_display!(FileType, Permission, Attribute);
+
+// Keys are sorted, the same as the map this was serialized as before being typed
+#[cfg(feature = "serde")]
+impl Serialize for Mode {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        let mut map = serializer.serialize_map(Some(5))?;
+        map.serialize_entry("attributes", &self.attributes)?;
+        map.serialize_entry("file_type", &self.file_type)?;
+        map.serialize_entry("group", &self.group)?;
+        map.serialize_entry("other", &self.other)?;
+        map.serialize_entry("user", &self.user)?;
+        map.end()
+    }
+}
+
 pub fn resolve_mode(mode: &str) -> Option<Mode> {
     let mode = u32::from_str_radix(mode, 8).ok()?;
 
+    Some(resolve_mode_bits(mode))
+}
+
+/// Resolves a numeric mode, such as the `mode_t` arguments of syscalls.
+pub fn resolve_mode_bits(mode: u32) -> Mode {
     let file_type = (mode & FILE_TYPE_MASK) >> 12;
     let attributes = (mode & ATTRIBUTES_MASK) >> 9;
     let user = (mode & USER_MASK) >> 6;
     let group = (mode & GROUP_MASK) >> 3;
     let other = mode & OTHER_MASK;
 
-    Some(Mode {
+    Mode {
         file_type: resolve_file_type(file_type),
         attributes: resolve_attributes(attributes),
         user: resolve_permissions(user),
         group: resolve_permissions(group),
         other: resolve_permissions(other),
-    })
+    }
 }
 
 fn resolve_attributes(attributes: u32) -> Vec<Attribute> {
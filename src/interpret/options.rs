@@ -0,0 +1,170 @@
+use std::collections::BTreeMap;
+use std::mem;
+
+use super::field_type::FieldType;
+
+/// Options to customize how the fields of a record are interpreted.
+///
+/// The default options interpret every field as [`AuditdRecord`](crate::AuditdRecord)
+/// has always done.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct InterpretOptions {
+    pub(crate) disabled_field_types: Vec<FieldType>,
+    pub(crate) name_style: NameStyle,
+    pub(crate) lowercase_names: bool,
+    pub(crate) uid_format: UidFormat,
+    pub(crate) keep_raw_values: bool,
+}
+
+/// Style of the names of kernel constants that are usually shown without their prefix,
+/// such as capabilities (`CAP_`) and socket families (`AF_`).
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum NameStyle {
+    /// Capabilities without prefix (`CHOWN`) and socket families with it (`AF_INET`)
+    #[default]
+    Default,
+    /// Names of the kernel constants, such as `CAP_CHOWN` and `AF_INET`
+    Kernel,
+    /// Names without prefix, such as `CHOWN` and `INET`
+    Unprefixed,
+}
+
+/// Representation of the ids of `uid` and `gid` fields.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub enum UidFormat {
+    /// `root` for the root user, `null` for unset ids and the number for the rest
+    #[default]
+    Default,
+    /// The number of every id, including unset ids (`4294967295` or `-1`, as they were logged)
+    Numeric,
+    /// The name of every id: the given names of users and groups, `root`, `unset`,
+    /// or `unknown(1000)` for the rest, as auparse shows them.
+    ///
+    /// Names are not looked up in the system, as records may be logged by other hosts.
+    Name {
+        users: BTreeMap<u32, String>,
+        groups: BTreeMap<u32, String>,
+    },
+}
+
+impl InterpretOptions {
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Leaves the fields of the given type uninterpreted, as strings.
+    ///
+    /// The index of [`FieldType::SyscallArg`] is ignored, so every syscall argument is disabled.
+    #[must_use]
+    pub fn disable(mut self, field_type: FieldType) -> Self {
+        if self.is_enabled(field_type) {
+            self.disabled_field_types.push(field_type);
+        }
+        self
+    }
+
+    /// Interprets the fields of the given type, which are all enabled by default.
+    #[must_use]
+    pub fn enable(mut self, field_type: FieldType) -> Self {
+        self.disabled_field_types
+            .retain(|disabled| mem::discriminant(disabled) != mem::discriminant(&field_type));
+        self
+    }
+
+    #[must_use]
+    pub fn name_style(mut self, name_style: NameStyle) -> Self {
+        self.name_style = name_style;
+        self
+    }
+
+    /// Lowercases the names styled with [`InterpretOptions::name_style`], such as `cap_chown`
+    /// as shown by `capsh`.
+    #[must_use]
+    pub fn lowercase_names(mut self, lowercase_names: bool) -> Self {
+        self.lowercase_names = lowercase_names;
+        self
+    }
+
+    #[must_use]
+    pub fn uid_format(mut self, uid_format: UidFormat) -> Self {
+        self.uid_format = uid_format;
+        self
+    }
+
+    /// Keeps the values of the fields as they were logged in
+    /// [`AuditdRecord::raw_fields`](crate::AuditdRecord::raw_fields), which are not kept
+    /// by default as each of them is copied.
+    #[must_use]
+    pub fn keep_raw_values(mut self, keep_raw_values: bool) -> Self {
+        self.keep_raw_values = keep_raw_values;
+        self
+    }
+
+    pub(crate) fn is_enabled(&self, field_type: FieldType) -> bool {
+        !self
+            .disabled_field_types
+            .iter()
+            .any(|disabled| mem::discriminant(disabled) == mem::discriminant(&field_type))
+    }
+
+    /// Whether names are shown as they are resolved, so they do not need to be styled
+    pub(crate) fn has_default_names(&self) -> bool {
+        self.name_style == NameStyle::Default && !self.lowercase_names
+    }
+
+    /// Styles the name of a kernel constant whose names start with the given prefix.
+    pub(crate) fn style_name(&self, name: &str, prefix: &str) -> String {
+        let name = match self.name_style {
+            NameStyle::Default => name.to_string(),
+            NameStyle::Kernel if name.starts_with(prefix) => name.to_string(),
+            NameStyle::Kernel => format!("{prefix}{name}"),
+            NameStyle::Unprefixed => name.strip_prefix(prefix).unwrap_or(name).to_string(),
+        };
+
+        if self.lowercase_names {
+            name.to_lowercase()
+        } else {
+            name
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use rstest::rstest;
+
+    use super::*;
+
+    #[rstest]
+    #[case::default_capability(NameStyle::Default, false, "CHOWN", "CAP_", "CHOWN")]
+    #[case::default_family(NameStyle::Default, false, "AF_INET", "AF_", "AF_INET")]
+    #[case::kernel_capability(NameStyle::Kernel, false, "CHOWN", "CAP_", "CAP_CHOWN")]
+    #[case::kernel_family(NameStyle::Kernel, false, "AF_INET", "AF_", "AF_INET")]
+    #[case::unprefixed_capability(NameStyle::Unprefixed, false, "CHOWN", "CAP_", "CHOWN")]
+    #[case::unprefixed_family(NameStyle::Unprefixed, false, "AF_INET", "AF_", "INET")]
+    #[case::lowercase_kernel(NameStyle::Kernel, true, "CHOWN", "CAP_", "cap_chown")]
+    #[case::lowercase_default(NameStyle::Default, true, "AF_INET", "AF_", "af_inet")]
+    fn test_style_name(
+        #[case] name_style: NameStyle,
+        #[case] lowercase_names: bool,
+        #[case] name: &str,
+        #[case] prefix: &str,
+        #[case] expected: &str,
+    ) {
+        let options = InterpretOptions::new()
+            .name_style(name_style)
+            .lowercase_names(lowercase_names);
+        assert_eq!(options.style_name(name, prefix), expected);
+    }
+
+    #[test]
+    fn test_disable_ignores_syscall_arg_index() {
+        let options = InterpretOptions::new().disable(FieldType::SyscallArg(0));
+        assert!(!options.is_enabled(FieldType::SyscallArg(3)));
+        assert!(options.is_enabled(FieldType::Syscall));
+
+        let options = options.enable(FieldType::SyscallArg(2));
+        assert!(options.is_enabled(FieldType::SyscallArg(0)));
+    }
+}
@@ -1,12 +1,17 @@
-pub fn parse_proctitle(bytes: &[u8]) -> String {
+pub fn parse_proctitle(bytes: &[u8]) -> Vec<String> {
     // Proctitle arguments are null-byte separated
     // Ref: https://github.com/linux-audit/audit-userspace/blob/747f67994b933fd70deed7d6f7cb0c40601f5bd1/auparse/interpret.c#L1000
-    bytes
+    let mut args = bytes
         .split(|&b| b == 0)
         .map(|arg| String::from_utf8_lossy(arg).to_string())
-        .collect::<Vec<String>>()
-        // TODO: join or not?
-        .join(" ")
+        .collect::<Vec<String>>();
+
+    // The last argument may be null-terminated, which is not an extra argument
+    if args.last().is_some_and(String::is_empty) {
+        args.pop();
+    }
+
+    args
 }
 
 #[cfg(test)]
@@ -15,12 +20,14 @@ mod tests {
     use rstest::rstest;
 
     #[rstest]
-    #[case::no_args("foo", "foo")]
-    #[case::single_arg("foo\0bar", "foo bar")]
-    #[case::multiple_args("foo\0bar\0baz", "foo bar baz")]
-    #[case::only_args("\0foo\0bar\0baz", " foo bar baz")]
-    #[case::empty("", "")]
-    fn test_parse_proctitle(#[case] input: &str, #[case] expected: &str) {
+    #[case::no_args("foo", vec!["foo"])]
+    #[case::single_arg("foo\0bar", vec!["foo", "bar"])]
+    #[case::multiple_args("foo\0bar\0baz", vec!["foo", "bar", "baz"])]
+    #[case::arg_with_spaces("rm\0a b", vec!["rm", "a b"])]
+    #[case::trailing_null("foo\0bar\0", vec!["foo", "bar"])]
+    #[case::only_args("\0foo\0bar\0baz", vec!["", "foo", "bar", "baz"])]
+    #[case::empty("", vec![])]
+    fn test_parse_proctitle(#[case] input: &str, #[case] expected: Vec<&str>) {
         let result = parse_proctitle(input.as_bytes());
         assert_eq!(result, expected);
     }
@@ -28,6 +35,6 @@ mod tests {
     #[test]
     fn test_parse_proctitle_non_utf8() {
         let result = parse_proctitle(b"foo\0b\xffar");
-        assert_eq!(result, "foo b�ar");
+        assert_eq!(result, vec!["foo", "b�ar"]);
     }
 }
@@ -1,21 +1,24 @@
 use std::fmt::{self, Display, Formatter};
 
-#[derive(Debug, PartialEq, Eq)]
-pub enum Result {
+use super::utils::impl_serialize_display;
+
+/// Result of an operation, from the `res` or `result` fields
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum AuditResult {
     Failed,
     Success,
     Unset,
 }
 
-pub fn resolve_result(result: &str) -> Result {
+pub fn resolve_result(result: &str) -> AuditResult {
     // First, try to parse it as a 32-bit unsigned integer
     // Ref: https://github.com/linux-audit/audit-userspace/blob/747f67994b933fd70deed7d6f7cb0c40601f5bd1/auparse/interpret.c#L1459
     if let Ok(result) = result.parse::<u32>() {
         // Ref: https://github.com/linux-audit/audit-userspace/blob/747f67994b933fd70deed7d6f7cb0c40601f5bd1/auparse/interpret.c#L540
         return match result {
-            0 => Result::Failed,
-            1 => Result::Success,
-            _ => Result::Unset,
+            0 => AuditResult::Failed,
+            1 => AuditResult::Success,
+            _ => AuditResult::Unset,
         };
     }
 
@@ -23,22 +26,24 @@ pub fn resolve_result(result: &str) -> Result {
     // Ref: https://github.com/linux-audit/audit-userspace/blob/747f67994b933fd70deed7d6f7cb0c40601f5bd1/auparse/interpret.c#L1469
     // Auparse does not validate the string, but we will match to it in order to have it typed
     match result {
-        "failed" => Result::Failed,
-        "success" => Result::Success,
-        _ => Result::Unset,
+        "failed" => AuditResult::Failed,
+        "success" => AuditResult::Success,
+        _ => AuditResult::Unset,
     }
 }
 
-impl Display for Result {
+impl Display for AuditResult {
     fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
         match self {
-            Result::Failed => write!(f, "failed"),
-            Result::Success => write!(f, "success"),
-            Result::Unset => write!(f, "unset"),
+            AuditResult::Failed => write!(f, "failed"),
+            AuditResult::Success => write!(f, "success"),
+            AuditResult::Unset => write!(f, "unset"),
         }
     }
 }
 
+impl_serialize_display!(AuditResult);
+
 #[cfg(test)]
 mod tests {
     use rstest::rstest;
@@ -46,13 +51,13 @@ mod tests {
     use super::*;
 
     #[rstest]
-    #[case::failed("0", Result::Failed)]
-    #[case::success("1", Result::Success)]
-    #[case::unset("2", Result::Unset)]
-    #[case::failed_string("failed", Result::Failed)]
-    #[case::success_string("success", Result::Success)]
-    #[case::foo("foo", Result::Unset)]
-    fn test_resolve_result(#[case] input: &str, #[case] expected: Result) {
+    #[case::failed("0", AuditResult::Failed)]
+    #[case::success("1", AuditResult::Success)]
+    #[case::unset("2", AuditResult::Unset)]
+    #[case::failed_string("failed", AuditResult::Failed)]
+    #[case::success_string("success", AuditResult::Success)]
+    #[case::foo("foo", AuditResult::Unset)]
+    fn test_resolve_result(#[case] input: &str, #[case] expected: AuditResult) {
         let result = resolve_result(input);
         assert_eq!(result, expected);
     }
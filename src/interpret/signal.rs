@@ -1,7 +1,10 @@
 use std::fmt::{self, Display, Formatter};
 
+use super::utils::impl_serialize_display;
+
 // Constants are extracted from https://github.com/torvalds/linux/blob/4a95bc121ccdaee04c4d72f84dbfa6b880a514b6/include/uapi/asm-generic/signal.h#L11
 // More information about signals can be found in https://man7.org/linux/man-pages/man7/signal.7.html
+/// Signal, such as the `sig` field of `OBJ_PID` records
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 #[allow(clippy::upper_case_acronyms)]
 pub enum Signal {
@@ -49,6 +52,8 @@ impl Display for Signal {
     }
 }
 
+impl_serialize_display!(Signal);
+
 impl TryFrom<u64> for Signal {
     type Error = ();
 
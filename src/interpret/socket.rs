@@ -1,13 +1,82 @@
 use std::net::{Ipv4Addr, Ipv6Addr, SocketAddrV4, SocketAddrV6};
 
 use bytes::{Buf, Bytes};
+#[cfg(feature = "serde")]
+use serde::{Serialize, Serializer, ser::SerializeMap};
 
 const AF_UNIX: u16 = 1;
 const AF_INET: u16 = 2;
 const AF_INET6: u16 = 10;
 const AF_NETLINK: u16 = 16;
 
-#[derive(Debug, PartialEq)]
+// Socket families, indexed by their number.
+// Constants extracted from https://github.com/torvalds/linux/blob/561c80369df0733ba0574882a1635287b20f9de2/include/linux/socket.h
+const SOCKET_FAMILIES: [&str; 46] = [
+    "AF_UNSPEC",
+    "AF_UNIX",
+    "AF_INET",
+    "AF_AX25",
+    "AF_IPX",
+    "AF_APPLETALK",
+    "AF_NETROM",
+    "AF_BRIDGE",
+    "AF_ATMPVC",
+    "AF_X25",
+    "AF_INET6",
+    "AF_ROSE",
+    "AF_DECnet",
+    "AF_NETBEUI",
+    "AF_SECURITY",
+    "AF_KEY",
+    "AF_NETLINK",
+    "AF_PACKET",
+    "AF_ASH",
+    "AF_ECONET",
+    "AF_ATMSVC",
+    "AF_RDS",
+    "AF_SNA",
+    "AF_IRDA",
+    "AF_PPPOX",
+    "AF_WANPIPE",
+    "AF_LLC",
+    "AF_IB",
+    "AF_MPLS",
+    "AF_CAN",
+    "AF_TIPC",
+    "AF_BLUETOOTH",
+    "AF_IUCV",
+    "AF_RXRPC",
+    "AF_ISDN",
+    "AF_PHONET",
+    "AF_IEEE802154",
+    "AF_CAIF",
+    "AF_ALG",
+    "AF_NFC",
+    "AF_VSOCK",
+    "AF_KCM",
+    "AF_QIPCRTR",
+    "AF_SMC",
+    "AF_XDP",
+    "AF_MCTP",
+];
+
+// Socket types and the flags that can be OR-ed to them in `socket(2)`
+// Ref: https://github.com/torvalds/linux/blob/561c80369df0733ba0574882a1635287b20f9de2/include/linux/net.h
+const SOCK_TYPE_MASK: u64 = 0xf;
+const SOCKET_TYPES: [(u64, &str); 7] = [
+    (1, "SOCK_STREAM"),
+    (2, "SOCK_DGRAM"),
+    (3, "SOCK_RAW"),
+    (4, "SOCK_RDM"),
+    (5, "SOCK_SEQPACKET"),
+    (6, "SOCK_DCCP"),
+    (10, "SOCK_PACKET"),
+];
+const SOCKET_TYPE_FLAGS: [(u64, &str); 2] =
+    [(0o4000, "SOCK_NONBLOCK"), (0o2_000_000, "SOCK_CLOEXEC")];
+
+/// Socket address decoded from the `saddr` field
+#[derive(Debug, Clone, PartialEq, Eq)]
 pub enum SocketAddr {
     Unix(SocketAddrLocal),
     Inet(SocketAddrV4),
@@ -16,6 +85,7 @@ pub enum SocketAddr {
 }
 
 impl SocketAddr {
+    #[must_use]
     pub fn family(&self) -> &'static str {
         match self {
             Self::Unix(_) => "AF_UNIX",
@@ -26,17 +96,83 @@ impl SocketAddr {
     }
 }
 
-#[derive(Debug, PartialEq)]
+#[cfg(feature = "serde")]
+impl Serialize for SocketAddr {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serialize_socket_addr(self, self.family(), serializer)
+    }
+}
+
+/// Serializes a socket address with the given name of its family, which may be styled.
+// Keys are sorted, the same as the map this was serialized as before being typed
+#[cfg(feature = "serde")]
+pub(crate) fn serialize_socket_addr<S: Serializer>(
+    address: &SocketAddr,
+    family: &str,
+    serializer: S,
+) -> Result<S::Ok, S::Error> {
+    let mut map = serializer.serialize_map(None)?;
+    match address {
+        SocketAddr::Unix(unix_address) => {
+            map.serialize_entry("family", family)?;
+            map.serialize_entry("path", &unix_address.path)?;
+        }
+        SocketAddr::Inet(inet_address) => {
+            map.serialize_entry("address", &inet_address.to_string())?;
+            map.serialize_entry("family", family)?;
+        }
+        SocketAddr::Inet6(inet6_address) => {
+            map.serialize_entry("address", &inet6_address.to_string())?;
+            map.serialize_entry("family", family)?;
+        }
+        SocketAddr::Netlink(netlink_address) => {
+            map.serialize_entry("family", family)?;
+            map.serialize_entry(
+                "multicast_groups_mask",
+                &netlink_address.multicast_groups_mask,
+            )?;
+            map.serialize_entry("port_id", &netlink_address.port_id)?;
+        }
+    }
+    map.end()
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
 pub struct SocketAddrLocal {
     pub path: String,
 }
 
-#[derive(Debug, PartialEq)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub struct SocketAddrNetlink {
     pub port_id: u32,
     pub multicast_groups_mask: u32,
 }
 
+/// Resolves the name of a socket family (also known as domain), such as `AF_PACKET`.
+pub fn resolve_socket_family(family: u64) -> Option<&'static str> {
+    let family = usize::try_from(family).ok()?;
+    SOCKET_FAMILIES.get(family).copied()
+}
+
+/// Resolves a socket type along with its flags, such as `SOCK_STREAM|SOCK_CLOEXEC`.
+/// Returns `None` when the type is unknown or the value has unknown flags.
+pub fn resolve_socket_type(socket_type: u64) -> Option<Vec<&'static str>> {
+    let (_, name) = SOCKET_TYPES
+        .iter()
+        .find(|(value, _)| *value == socket_type & SOCK_TYPE_MASK)?;
+
+    let mut remaining = socket_type & !SOCK_TYPE_MASK;
+    let mut result = vec![*name];
+    for (flag, flag_name) in SOCKET_TYPE_FLAGS {
+        if remaining & flag == flag {
+            result.push(flag_name);
+            remaining &= !flag;
+        }
+    }
+
+    (remaining == 0).then_some(result)
+}
+
 // We will parse the `sockaddr` struct memory layout.
 // This parsing will be very sensitive of the host machine's endianness.
 // Therefore, we will assume that the machine's endianness is little-endian (it is the most common one)
@@ -191,6 +327,25 @@ mod tests {
         }
     }
 
+    #[rstest]
+    #[case::unix(1, Some("AF_UNIX"))]
+    #[case::packet(17, Some("AF_PACKET"))]
+    #[case::mctp(45, Some("AF_MCTP"))]
+    #[case::unknown(46, None)]
+    fn test_resolve_socket_family(#[case] input: u64, #[case] expected: Option<&str>) {
+        assert_eq!(resolve_socket_family(input), expected);
+    }
+
+    #[rstest]
+    #[case::stream(0x1, Some(vec!["SOCK_STREAM"]))]
+    #[case::raw_cloexec(0x80003, Some(vec!["SOCK_RAW", "SOCK_CLOEXEC"]))]
+    #[case::dgram_nonblock_cloexec(0x80802, Some(vec!["SOCK_DGRAM", "SOCK_NONBLOCK", "SOCK_CLOEXEC"]))]
+    #[case::unknown_type(0x7, None)]
+    #[case::unknown_flag(0x101, None)]
+    fn test_resolve_socket_type(#[case] input: u64, #[case] expected: Option<Vec<&str>>) {
+        assert_eq!(resolve_socket_type(input), expected);
+    }
+
     #[rstest]
     #[case::af_unix("01002F7661722F72756E2F6E7363642F736F636B6574", "/var/run/nscd/socket")]
     #[case::af_inet("02000050A9FEA9FE", "169.254.169.254:80")]
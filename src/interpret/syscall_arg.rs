@@ -0,0 +1,518 @@
+// Interpretation of the `a0`..`a3` arguments of SYSCALL records, which depends on the syscall
+// that was called. Based on `print_a0`..`print_a3` from auparse
+// https://github.com/linux-audit/audit-userspace/blob/747f67994b933fd70deed7d6f7cb0c40601f5bd1/auparse/interpret.c
+
+use super::arch::AuditArch;
+use super::mode::{self, Mode};
+use super::signal::Signal;
+use super::socket;
+
+// Ref: https://github.com/torvalds/linux/blob/561c80369df0733ba0574882a1635287b20f9de2/include/uapi/linux/fcntl.h
+// It is an `int`, so it is logged either sign-extended to 64 bits or as 32 bits
+const AT_FDCWD: u64 = 0xffff_ff9c;
+
+// Ref: https://github.com/torvalds/linux/blob/561c80369df0733ba0574882a1635287b20f9de2/include/uapi/asm-generic/fcntl.h
+const O_ACCMODE: u64 = 0o3;
+const OPEN_ACCESS_MODES: [(u64, &str); 3] = [(0o0, "O_RDONLY"), (0o1, "O_WRONLY"), (0o2, "O_RDWR")];
+// `O_SYNC` is `__O_SYNC | O_DSYNC`, so it goes before `O_DSYNC`, which may be set on its own
+const OPEN_FLAGS: [(u64, &str); 13] = [
+    (0o4_010_000, "O_SYNC"),
+    (0o100, "O_CREAT"),
+    (0o200, "O_EXCL"),
+    (0o400, "O_NOCTTY"),
+    (0o1000, "O_TRUNC"),
+    (0o2000, "O_APPEND"),
+    (0o4000, "O_NONBLOCK"),
+    (0o10000, "O_DSYNC"),
+    (0o20000, "FASYNC"),
+    (0o1_000_000, "O_NOATIME"),
+    (0o2_000_000, "O_CLOEXEC"),
+    (0o10_000_000, "O_PATH"),
+    (0o20_000_000, "O_TMPFILE"),
+];
+// Those flags have different values depending on the architecture
+const OPEN_FLAGS_GENERIC: [(u64, &str); 4] = [
+    (0o40000, "O_DIRECT"),
+    (0o100_000, "O_LARGEFILE"),
+    (0o200_000, "O_DIRECTORY"),
+    (0o400_000, "O_NOFOLLOW"),
+];
+// Ref: https://github.com/torvalds/linux/blob/561c80369df0733ba0574882a1635287b20f9de2/arch/arm64/include/uapi/asm/fcntl.h
+const OPEN_FLAGS_ARM: [(u64, &str); 4] = [
+    (0o40000, "O_DIRECTORY"),
+    (0o100_000, "O_NOFOLLOW"),
+    (0o200_000, "O_DIRECT"),
+    (0o400_000, "O_LARGEFILE"),
+];
+// Ref: https://github.com/torvalds/linux/blob/561c80369df0733ba0574882a1635287b20f9de2/arch/powerpc/include/uapi/asm/fcntl.h
+const OPEN_FLAGS_PPC: [(u64, &str); 4] = [
+    (0o40000, "O_DIRECTORY"),
+    (0o100_000, "O_NOFOLLOW"),
+    (0o200_000, "O_LARGEFILE"),
+    (0o400_000, "O_DIRECT"),
+];
+
+// Ref: https://github.com/torvalds/linux/blob/561c80369df0733ba0574882a1635287b20f9de2/include/uapi/linux/sched.h
+const CSIGNAL: u64 = 0xff;
+const CLONE_FLAGS: [(u64, &str); 24] = [
+    (0x0000_0100, "CLONE_VM"),
+    (0x0000_0200, "CLONE_FS"),
+    (0x0000_0400, "CLONE_FILES"),
+    (0x0000_0800, "CLONE_SIGHAND"),
+    (0x0000_1000, "CLONE_PIDFD"),
+    (0x0000_2000, "CLONE_PTRACE"),
+    (0x0000_4000, "CLONE_VFORK"),
+    (0x0000_8000, "CLONE_PARENT"),
+    (0x0001_0000, "CLONE_THREAD"),
+    (0x0002_0000, "CLONE_NEWNS"),
+    (0x0004_0000, "CLONE_SYSVSEM"),
+    (0x0008_0000, "CLONE_SETTLS"),
+    (0x0010_0000, "CLONE_PARENT_SETTID"),
+    (0x0020_0000, "CLONE_CHILD_CLEARTID"),
+    (0x0040_0000, "CLONE_DETACHED"),
+    (0x0080_0000, "CLONE_UNTRACED"),
+    (0x0100_0000, "CLONE_CHILD_SETTID"),
+    (0x0200_0000, "CLONE_NEWCGROUP"),
+    (0x0400_0000, "CLONE_NEWUTS"),
+    (0x0800_0000, "CLONE_NEWIPC"),
+    (0x1000_0000, "CLONE_NEWUSER"),
+    (0x2000_0000, "CLONE_NEWPID"),
+    (0x4000_0000, "CLONE_NEWNET"),
+    (0x8000_0000, "CLONE_IO"),
+];
+
+// Ref: https://github.com/torvalds/linux/blob/561c80369df0733ba0574882a1635287b20f9de2/include/uapi/asm-generic/mman-common.h
+const PROT_FLAGS: [(u64, &str); 6] = [
+    (0x1, "PROT_READ"),
+    (0x2, "PROT_WRITE"),
+    (0x4, "PROT_EXEC"),
+    (0x8, "PROT_SEM"),
+    (0x0100_0000, "PROT_GROWSDOWN"),
+    (0x0200_0000, "PROT_GROWSUP"),
+];
+const MAP_TYPE: u64 = 0xf;
+const MAP_TYPES: [(u64, &str); 3] = [
+    (0x1, "MAP_SHARED"),
+    (0x2, "MAP_PRIVATE"),
+    (0x3, "MAP_SHARED_VALIDATE"),
+];
+// Ref: https://github.com/torvalds/linux/blob/561c80369df0733ba0574882a1635287b20f9de2/include/uapi/asm-generic/mman.h
+const MAP_FLAGS: [(u64, &str); 14] = [
+    (0x10, "MAP_FIXED"),
+    (0x20, "MAP_ANONYMOUS"),
+    (0x100, "MAP_GROWSDOWN"),
+    (0x800, "MAP_DENYWRITE"),
+    (0x1000, "MAP_EXECUTABLE"),
+    (0x2000, "MAP_LOCKED"),
+    (0x4000, "MAP_NORESERVE"),
+    (0x8000, "MAP_POPULATE"),
+    (0x1_0000, "MAP_NONBLOCK"),
+    (0x2_0000, "MAP_STACK"),
+    (0x4_0000, "MAP_HUGETLB"),
+    (0x8_0000, "MAP_SYNC"),
+    (0x10_0000, "MAP_FIXED_NOREPLACE"),
+    (0x400_0000, "MAP_UNINITIALIZED"),
+];
+
+// Ref: https://github.com/torvalds/linux/blob/561c80369df0733ba0574882a1635287b20f9de2/include/uapi/linux/ptrace.h
+const PTRACE_REQUESTS: [(u64, &str); 20] = [
+    (0, "PTRACE_TRACEME"),
+    (1, "PTRACE_PEEKTEXT"),
+    (2, "PTRACE_PEEKDATA"),
+    (3, "PTRACE_PEEKUSR"),
+    (4, "PTRACE_POKETEXT"),
+    (5, "PTRACE_POKEDATA"),
+    (6, "PTRACE_POKEUSR"),
+    (7, "PTRACE_CONT"),
+    (8, "PTRACE_KILL"),
+    (9, "PTRACE_SINGLESTEP"),
+    (16, "PTRACE_ATTACH"),
+    (17, "PTRACE_DETACH"),
+    (24, "PTRACE_SYSCALL"),
+    (0x4200, "PTRACE_SETOPTIONS"),
+    (0x4201, "PTRACE_GETEVENTMSG"),
+    (0x4202, "PTRACE_GETSIGINFO"),
+    (0x4203, "PTRACE_SETSIGINFO"),
+    (0x4204, "PTRACE_GETREGSET"),
+    (0x4205, "PTRACE_SETREGSET"),
+    (0x4206, "PTRACE_SEIZE"),
+];
+
+// Ref: https://github.com/torvalds/linux/blob/561c80369df0733ba0574882a1635287b20f9de2/include/uapi/asm-generic/ioctls.h
+// PowerPC uses different values, so they are not resolved for it
+const IOCTL_REQUESTS: [(u64, &str); 40] = [
+    (0x5401, "TCGETS"),
+    (0x5402, "TCSETS"),
+    (0x5403, "TCSETSW"),
+    (0x5404, "TCSETSF"),
+    (0x5405, "TCGETA"),
+    (0x5406, "TCSETA"),
+    (0x5407, "TCSETAW"),
+    (0x5408, "TCSETAF"),
+    (0x5409, "TCSBRK"),
+    (0x540a, "TCXONC"),
+    (0x540b, "TCFLSH"),
+    (0x540c, "TIOCEXCL"),
+    (0x540d, "TIOCNXCL"),
+    (0x540e, "TIOCSCTTY"),
+    (0x540f, "TIOCGPGRP"),
+    (0x5410, "TIOCSPGRP"),
+    (0x5411, "TIOCOUTQ"),
+    (0x5412, "TIOCSTI"),
+    (0x5413, "TIOCGWINSZ"),
+    (0x5414, "TIOCSWINSZ"),
+    (0x5415, "TIOCMGET"),
+    (0x5416, "TIOCMBIS"),
+    (0x5417, "TIOCMBIC"),
+    (0x5418, "TIOCMSET"),
+    (0x5419, "TIOCGSOFTCAR"),
+    (0x541a, "TIOCSSOFTCAR"),
+    (0x541b, "FIONREAD"),
+    (0x541c, "TIOCLINUX"),
+    (0x541d, "TIOCCONS"),
+    (0x541e, "TIOCGSERIAL"),
+    (0x541f, "TIOCSSERIAL"),
+    (0x5420, "TIOCPKT"),
+    (0x5421, "FIONBIO"),
+    (0x5422, "TIOCNOTTY"),
+    (0x5423, "TIOCSETD"),
+    (0x5424, "TIOCGETD"),
+    (0x5425, "TCSBRKP"),
+    (0x5450, "FIONCLEX"),
+    (0x5451, "FIOCLEX"),
+    (0x5452, "FIOASYNC"),
+];
+
+// Ref: https://github.com/torvalds/linux/blob/561c80369df0733ba0574882a1635287b20f9de2/include/uapi/asm-generic/signal-defs.h
+const SIGPROCMASK_HOWS: [(u64, &str); 3] =
+    [(0, "SIG_BLOCK"), (1, "SIG_UNBLOCK"), (2, "SIG_SETMASK")];
+
+// Ref: https://github.com/torvalds/linux/blob/561c80369df0733ba0574882a1635287b20f9de2/include/uapi/linux/time.h
+const CLOCK_IDS: [(u64, &str); 11] = [
+    (0, "CLOCK_REALTIME"),
+    (1, "CLOCK_MONOTONIC"),
+    (2, "CLOCK_PROCESS_CPUTIME_ID"),
+    (3, "CLOCK_THREAD_CPUTIME_ID"),
+    (4, "CLOCK_MONOTONIC_RAW"),
+    (5, "CLOCK_REALTIME_COARSE"),
+    (6, "CLOCK_MONOTONIC_COARSE"),
+    (7, "CLOCK_BOOTTIME"),
+    (8, "CLOCK_REALTIME_ALARM"),
+    (9, "CLOCK_BOOTTIME_ALARM"),
+    (11, "CLOCK_TAI"),
+];
+
+// Ref: https://github.com/torvalds/linux/blob/561c80369df0733ba0574882a1635287b20f9de2/include/uapi/linux/net.h
+const SOCKETCALL_CALLS: [(u64, &str); 20] = [
+    (1, "socket"),
+    (2, "bind"),
+    (3, "connect"),
+    (4, "listen"),
+    (5, "accept"),
+    (6, "getsockname"),
+    (7, "getpeername"),
+    (8, "socketpair"),
+    (9, "send"),
+    (10, "recv"),
+    (11, "sendto"),
+    (12, "recvfrom"),
+    (13, "shutdown"),
+    (14, "setsockopt"),
+    (15, "getsockopt"),
+    (16, "sendmsg"),
+    (17, "recvmsg"),
+    (18, "accept4"),
+    (19, "recvmmsg"),
+    (20, "sendmmsg"),
+];
+
+// Syscalls whose first argument is a directory file descriptor
+const DIRFD_SYSCALLS: [&str; 19] = [
+    "openat",
+    "openat2",
+    "mkdirat",
+    "mknodat",
+    "fchownat",
+    "futimesat",
+    "newfstatat",
+    "fstatat64",
+    "unlinkat",
+    "renameat",
+    "renameat2",
+    "linkat",
+    "readlinkat",
+    "fchmodat",
+    "fchmodat2",
+    "faccessat",
+    "faccessat2",
+    "utimensat",
+    "execveat",
+];
+
+#[derive(Debug, PartialEq)]
+pub enum SyscallArg {
+    /// Named constant, such as `AF_PACKET` or `PTRACE_ATTACH`
+    Constant(&'static str),
+    /// Names of the flags that are set, such as `O_WRONLY` and `O_CREAT`
+    Flags(Vec<String>),
+    Mode(Mode),
+    Signal(Signal),
+}
+
+/// Resolves the argument at `index` (0 for `a0`) of the given syscall.
+/// Returns `None` when the argument is not interpreted for that syscall (e.g. pointers)
+/// or when its value is unknown.
+pub fn resolve_syscall_arg(
+    arch: AuditArch,
+    syscall: &str,
+    index: usize,
+    value: u64,
+) -> Option<SyscallArg> {
+    // On i386 `mmap` receives a pointer to its arguments, `mmap2` is used instead
+    if syscall == "mmap" && arch == AuditArch::I386 {
+        return None;
+    }
+
+    match (syscall, index) {
+        ("socket" | "socketpair", 0) => {
+            socket::resolve_socket_family(value).map(SyscallArg::Constant)
+        }
+        ("socket" | "socketpair", 1) => socket::resolve_socket_type(value)
+            .map(|names| SyscallArg::Flags(names.into_iter().map(String::from).collect())),
+        ("socketcall", 0) => resolve_constant(value, &SOCKETCALL_CALLS),
+        // s390 swaps the first two arguments of clone (`CONFIG_CLONE_BACKWARDS2`)
+        ("clone", 0) if arch != AuditArch::S390X => Some(resolve_clone_flags(value)),
+        ("clone", 1) if arch == AuditArch::S390X => Some(resolve_clone_flags(value)),
+        ("unshare", 0) => Some(SyscallArg::Flags(resolve_flags(value, &CLONE_FLAGS))),
+        ("ptrace", 0) => resolve_constant(value, &PTRACE_REQUESTS),
+        ("rt_sigprocmask" | "sigprocmask", 0) => resolve_constant(value, &SIGPROCMASK_HOWS),
+        ("clock_gettime" | "clock_settime" | "clock_getres" | "clock_nanosleep", 0) => {
+            resolve_constant(value, &CLOCK_IDS)
+        }
+        ("rt_sigaction" | "sigaction" | "signal", 0) | ("kill" | "tkill", 1) | ("tgkill", 2) => {
+            resolve_signal(value)
+        }
+        ("open", 1) | ("openat", 2) => Some(resolve_open_flags(arch, value)),
+        ("chmod" | "fchmod" | "mkdir" | "creat" | "mknod", 1)
+        | ("fchmodat" | "fchmodat2" | "mkdirat" | "mknodat", 2) => resolve_mode(value),
+        ("ioctl", 1) if !is_powerpc(arch) => resolve_constant(value, &IOCTL_REQUESTS),
+        ("mmap" | "mmap2" | "mprotect" | "pkey_mprotect", 2) => Some(resolve_prot_flags(value)),
+        // PowerPC uses different values for some of the flags
+        ("mmap" | "mmap2", 3) if !is_powerpc(arch) => resolve_map_flags(value),
+        (syscall, 0) if DIRFD_SYSCALLS.contains(&syscall) => resolve_dirfd(value),
+        ("renameat" | "renameat2" | "linkat", 2) | ("symlinkat", 1) => resolve_dirfd(value),
+        _ => None,
+    }
+}
+
+fn is_powerpc(arch: AuditArch) -> bool {
+    matches!(arch, AuditArch::PPC64 | AuditArch::PPC64LE)
+}
+
+fn resolve_constant(value: u64, constants: &[(u64, &'static str)]) -> Option<SyscallArg> {
+    constants
+        .iter()
+        .find(|(constant, _)| *constant == value)
+        .map(|(_, name)| SyscallArg::Constant(name))
+}
+
+/// Resolves the flags set in `value`. Bits that do not belong to any known flag are kept
+/// as a hexadecimal number, so no information is lost. Flags made of several bits must go
+/// before the flags of their bits, which are not resolved once the former is.
+fn resolve_flags(value: u64, flags: &[(u64, &str)]) -> Vec<String> {
+    let mut remaining = value;
+    let mut result = Vec::new();
+    for (flag, name) in flags {
+        if remaining & flag == *flag {
+            result.push((*name).to_string());
+            remaining &= !flag;
+        }
+    }
+
+    if remaining != 0 {
+        result.push(format!("0x{remaining:x}"));
+    }
+
+    result
+}
+
+fn resolve_open_flags(arch: AuditArch, value: u64) -> SyscallArg {
+    let arch_flags = match arch {
+        AuditArch::ARM | AuditArch::AARCH64 => &OPEN_FLAGS_ARM,
+        AuditArch::PPC64 | AuditArch::PPC64LE => &OPEN_FLAGS_PPC,
+        _ => &OPEN_FLAGS_GENERIC,
+    };
+
+    let mut result = resolve_flags(value & O_ACCMODE, &OPEN_ACCESS_MODES[1..]);
+    if result.is_empty() {
+        result.push(OPEN_ACCESS_MODES[0].1.to_string());
+    }
+
+    let flags = [&OPEN_FLAGS[..], &arch_flags[..]].concat();
+    result.extend(resolve_flags(value & !O_ACCMODE, &flags));
+
+    SyscallArg::Flags(result)
+}
+
+fn resolve_clone_flags(value: u64) -> SyscallArg {
+    let mut result = resolve_flags(value & !CSIGNAL, &CLONE_FLAGS);
+
+    // The lowest byte is the signal sent to the parent when the child exits
+    let exit_signal = value & CSIGNAL;
+    if exit_signal != 0 {
+        let exit_signal = Signal::try_from(exit_signal).map_or_else(
+            |()| format!("0x{exit_signal:x}"),
+            |signal| signal.to_string(),
+        );
+        result.push(exit_signal);
+    }
+
+    SyscallArg::Flags(result)
+}
+
+fn resolve_prot_flags(value: u64) -> SyscallArg {
+    if value == 0 {
+        return SyscallArg::Flags(vec!["PROT_NONE".to_string()]);
+    }
+
+    SyscallArg::Flags(resolve_flags(value, &PROT_FLAGS))
+}
+
+fn resolve_map_flags(value: u64) -> Option<SyscallArg> {
+    let (_, map_type) = MAP_TYPES
+        .iter()
+        .find(|(map_type, _)| *map_type == value & MAP_TYPE)?;
+
+    let mut result = vec![(*map_type).to_string()];
+    result.extend(resolve_flags(value & !MAP_TYPE, &MAP_FLAGS));
+
+    Some(SyscallArg::Flags(result))
+}
+
+fn resolve_signal(value: u64) -> Option<SyscallArg> {
+    Signal::try_from(value).ok().map(SyscallArg::Signal)
+}
+
+fn resolve_mode(value: u64) -> Option<SyscallArg> {
+    let mode = u32::try_from(value).ok()?;
+    Some(SyscallArg::Mode(mode::resolve_mode_bits(mode)))
+}
+
+fn resolve_dirfd(value: u64) -> Option<SyscallArg> {
+    (value & 0xffff_ffff == AT_FDCWD).then_some(SyscallArg::Constant("AT_FDCWD"))
+}
+
+#[cfg(test)]
+mod tests {
+    use rstest::rstest;
+
+    use super::*;
+
+    fn flags(names: &[&str]) -> SyscallArg {
+        SyscallArg::Flags(names.iter().map(ToString::to_string).collect())
+    }
+
+    #[rstest]
+    #[case::socket_family(
+        AuditArch::X86_64,
+        "socket",
+        0,
+        0x11,
+        Some(SyscallArg::Constant("AF_PACKET"))
+    )]
+    #[case::socket_type(AuditArch::X86_64, "socket", 1, 0x80003, Some(flags(&["SOCK_RAW", "SOCK_CLOEXEC"])))]
+    #[case::socketcall(
+        AuditArch::I386,
+        "socketcall",
+        0,
+        0x2,
+        Some(SyscallArg::Constant("bind"))
+    )]
+    #[case::ptrace_attach(
+        AuditArch::X86_64,
+        "ptrace",
+        0,
+        0x10,
+        Some(SyscallArg::Constant("PTRACE_ATTACH"))
+    )]
+    #[case::kill(
+        AuditArch::X86_64,
+        "kill",
+        1,
+        0x9,
+        Some(SyscallArg::Signal(Signal::SIGKILL))
+    )]
+    #[case::tgkill(
+        AuditArch::X86_64,
+        "tgkill",
+        2,
+        0xf,
+        Some(SyscallArg::Signal(Signal::SIGTERM))
+    )]
+    #[case::unknown_signal(AuditArch::X86_64, "kill", 1, 0x99, None)]
+    #[case::open_rdonly(AuditArch::X86_64, "open", 1, 0x10800, Some(flags(&["O_RDONLY", "O_NONBLOCK", "O_DIRECTORY"])))]
+    #[case::openat_write(AuditArch::X86_64, "openat", 2, 0x241, Some(flags(&["O_WRONLY", "O_CREAT", "O_TRUNC"])))]
+    #[case::open_sync(AuditArch::X86_64, "open", 1, 0o4_010_001, Some(flags(&["O_WRONLY", "O_SYNC"])))]
+    #[case::open_dsync(AuditArch::X86_64, "open", 1, 0o10_001, Some(flags(&["O_WRONLY", "O_DSYNC"])))]
+    #[case::openat_aarch64(AuditArch::AARCH64, "openat", 2, 0x4000, Some(flags(&["O_RDONLY", "O_DIRECTORY"])))]
+    #[case::openat_dirfd(
+        AuditArch::X86_64,
+        "openat",
+        0,
+        0xffff_ff9c,
+        Some(SyscallArg::Constant("AT_FDCWD"))
+    )]
+    #[case::openat_dirfd_sign_extended(
+        AuditArch::X86_64,
+        "openat",
+        0,
+        0xffff_ffff_ffff_ff9c,
+        Some(SyscallArg::Constant("AT_FDCWD"))
+    )]
+    #[case::openat_regular_dirfd(AuditArch::X86_64, "openat", 0, 0x3, None)]
+    #[case::clone(AuditArch::X86_64, "clone", 0, 0x0120_0011, Some(flags(&["CLONE_CHILD_CLEARTID", "CLONE_CHILD_SETTID", "SIGCHLD"])))]
+    #[case::clone_s390x(AuditArch::S390X, "clone", 1, 0x2000_0000, Some(flags(&["CLONE_NEWPID"])))]
+    #[case::clone_s390x_stack(AuditArch::S390X, "clone", 0, 0x2000_0000, None)]
+    #[case::mmap_prot(AuditArch::X86_64, "mmap", 2, 0x5, Some(flags(&["PROT_READ", "PROT_EXEC"])))]
+    #[case::mmap_prot_none(AuditArch::X86_64, "mprotect", 2, 0x0, Some(flags(&["PROT_NONE"])))]
+    #[case::mmap_flags(AuditArch::X86_64, "mmap", 3, 0x22, Some(flags(&["MAP_PRIVATE", "MAP_ANONYMOUS"])))]
+    #[case::mmap_unknown_flag(AuditArch::X86_64, "mmap", 3, 0x0800_0002, Some(flags(&["MAP_PRIVATE", "0x8000000"])))]
+    #[case::old_mmap_i386(AuditArch::I386, "mmap", 2, 0x5, None)]
+    #[case::ioctl(
+        AuditArch::X86_64,
+        "ioctl",
+        1,
+        0x5412,
+        Some(SyscallArg::Constant("TIOCSTI"))
+    )]
+    #[case::ioctl_ppc64le(AuditArch::PPC64LE, "ioctl", 1, 0x5412, None)]
+    #[case::rt_sigprocmask(
+        AuditArch::X86_64,
+        "rt_sigprocmask",
+        0,
+        0x2,
+        Some(SyscallArg::Constant("SIG_SETMASK"))
+    )]
+    #[case::pointer_argument(AuditArch::X86_64, "open", 0, 0x5555_665d_91b0, None)]
+    #[case::unknown_syscall(AuditArch::X86_64, "foo", 0, 0x1, None)]
+    fn test_resolve_syscall_arg(
+        #[case] arch: AuditArch,
+        #[case] syscall: &str,
+        #[case] index: usize,
+        #[case] value: u64,
+        #[case] expected: Option<SyscallArg>,
+    ) {
+        let result = resolve_syscall_arg(arch, syscall, index, value);
+        assert_eq!(result, expected);
+    }
+
+    #[test]
+    fn test_resolve_syscall_arg_mode() {
+        let Some(SyscallArg::Mode(mode)) =
+            resolve_syscall_arg(AuditArch::X86_64, "chmod", 1, 0o4755)
+        else {
+            panic!("chmod mode should be resolved");
+        };
+        assert_eq!(mode, mode::resolve_mode("4755").unwrap());
+    }
+}
@@ -7,3 +7,23 @@ pub fn into_string_array_to_field_value<T: ToString>(array: &[T]) -> FieldValue
         .collect::<Vec<String>>()
         .into()
 }
+
+/// Implements `Serialize` for types that are serialized as their `Display` representation,
+/// which is how they were represented as a [`FieldValue::String`].
+macro_rules! impl_serialize_display {
+    ($($ty:ty),+ $(,)?) => {
+        $(
+            #[cfg(feature = "serde")]
+            impl serde::Serialize for $ty {
+                fn serialize<S: serde::Serializer>(
+                    &self,
+                    serializer: S,
+                ) -> ::std::result::Result<S::Ok, S::Error> {
+                    serializer.collect_str(self)
+                }
+            }
+        )+
+    };
+}
+
+pub(crate) use impl_serialize_display;
@@ -1,11 +1,30 @@
+mod event;
+mod fields;
 mod interpret;
 // TODO: remove this pub(crate) once refactor `interpret_key_value_field`
 pub(crate) mod parser;
+mod reader;
 mod record;
+mod record_type;
+mod timestamp;
 
-pub use parser::ParserError;
+pub use event::{AuditEvent, EventAggregator};
+pub use fields::Fields;
+pub use interpret::{
+    AppArmorLabel, AppArmorMode, Attribute, AuditArch, AuditFlag, AuditResult, Errno, FieldType,
+    FileType, InterpretOptions, MacLabel, Mode, NameStyle, Permission, SELinuxContext,
+    SELinuxLevel, Signal, SocketAddr, SocketAddrLocal, SocketAddrNetlink, UidFormat,
+};
+pub use parser::{ParseOptions, ParserError, RawAuditdRecord, RawFields, RawValue};
+pub use reader::{AuditLogReader, ReadRecord};
 pub use record::AuditdRecord;
 pub use record::FieldValue;
+#[cfg(feature = "serde")]
+pub use record::{WithRawValues, WithTimestampFormat};
+pub use record_type::RecordType;
+pub use timestamp::{AuditTimestamp, ParseTimestampError};
+#[cfg(feature = "serde")]
+pub use timestamp::{TimestampFormat, rfc3339};
 
 #[cfg(test)]
 mod tests {
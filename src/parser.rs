@@ -1,95 +1,350 @@
-use std::collections::BTreeMap;
+use std::borrow::Cow;
 
-use body::parse_body;
+use body::{LenientBody, parse_body, parse_lenient_body, parse_partial_body};
 use header::parse_header;
-use nom::{Finish, Parser};
-#[cfg(feature = "serde")]
-use serde::{Deserialize, Serialize};
-use thiserror::Error;
+use invalid_utf8::escape_invalid_utf8;
+use nom::Finish;
+
+use crate::{AuditTimestamp, Fields, InterpretOptions, RecordType};
 
 // TODO: remove pub(crate) once refactor `interpret_key_value_field`
 pub(crate) mod body;
+mod error;
 mod header;
+mod invalid_utf8;
+
+pub use body::{RawFields, RawValue};
+pub use error::ParserError;
+pub use invalid_utf8::unescape_bytes;
 
+/// Record as found in the log, borrowing its fields from the parsed line.
 #[derive(Debug, Clone, PartialEq, Eq)]
-pub struct RawAuditdRecord {
+pub struct RawAuditdRecord<'a> {
     // TODO: rename `record_type` to `type`?
-    pub record_type: String,
-    /// Unix timestamp in milliseconds
-    pub timestamp: u64,
+    pub record_type: RecordType,
+    /// Timestamp shared by all the records of the event, `None` for records logged as `msg=?`
+    pub timestamp: Option<AuditTimestamp>,
 
-    /// Record identifier
-    pub id: u64,
+    /// Record identifier, `None` for records logged as `msg=?`
+    pub id: Option<u64>,
 
     /// Node field
-    pub node: Option<String>,
+    pub node: Option<Cow<'a, str>>,
+
+    pub fields: RawFields<'a>,
+
+    pub enrichment: Option<Fields<Cow<'a, str>, Cow<'a, str>>>,
+
+    /// Segments of the body that were skipped when parsing leniently
+    pub unparsed: Vec<Cow<'a, str>>,
+
+    /// Errors of the skipped segments, in the same order as `unparsed`
+    pub warnings: Vec<ParserError>,
+
+    /// Whether the line was not valid UTF-8, so its invalid bytes are escaped in the keys
+    /// and values (see [`RawAuditdRecord::field_bytes`])
+    pub invalid_utf8: bool,
+}
+
+impl<'a> RawAuditdRecord<'a> {
+    /// Parses a record without copying its fields, which borrow the input.
+    ///
+    /// # Errors
+    ///
+    /// Returns a [`ParserError`] if the line is not a valid record.
+    pub fn parse(input: &'a str) -> Result<Self, ParserError> {
+        parse_record(input)
+    }
+
+    /// Parses a record without copying its fields, with the given options.
+    ///
+    /// # Errors
+    ///
+    /// Returns a [`ParserError`] if the line is not a valid record. Lenient parsing
+    /// only fails when the header is not valid.
+    pub fn parse_with(input: &'a str, options: &ParseOptions) -> Result<Self, ParserError> {
+        parse_record_with(input, options)
+    }
 
-    // TODO: use index-ordered map?
-    pub fields: BTreeMap<String, String>,
+    /// Parses a record from a line that may not be valid UTF-8. Fields are borrowed from
+    /// valid lines, while the invalid bytes of other lines are kept escaped in the values
+    /// (see [`RawAuditdRecord::field_bytes`]), so they are not lost.
+    ///
+    /// # Errors
+    ///
+    /// Returns a [`ParserError`] if the line is not a valid record.
+    pub fn parse_bytes(input: &'a [u8]) -> Result<Self, ParserError> {
+        parse_record_bytes(input, &ParseOptions::default())
+    }
+
+    /// Parses a record from a line that may not be valid UTF-8, with the given options.
+    ///
+    /// # Errors
+    ///
+    /// Returns a [`ParserError`] if the line is not a valid record. Lenient parsing
+    /// only fails when the header is not valid.
+    pub fn parse_bytes_with(input: &'a [u8], options: &ParseOptions) -> Result<Self, ParserError> {
+        parse_record_bytes(input, options)
+    }
+
+    /// Returns the bytes of the first value of the given key, restoring the invalid UTF-8 bytes
+    /// of lines parsed with [`RawAuditdRecord::parse_bytes`].
+    #[must_use]
+    pub fn field_bytes(&self, key: &str) -> Option<Cow<'_, [u8]>> {
+        let value = self.fields.get(key)?.as_str();
+        match unescape_bytes(value).filter(|_| self.invalid_utf8) {
+            Some(bytes) => Some(Cow::Owned(bytes)),
+            None => Some(Cow::Borrowed(value.as_bytes())),
+        }
+    }
 
-    // TODO: use index-ordered map?
-    pub enrichment: Option<BTreeMap<String, String>>,
+    /// Copies the borrowed data of the record, so it outlives the parsed line.
+    #[must_use]
+    pub fn into_owned(self) -> RawAuditdRecord<'static> {
+        RawAuditdRecord {
+            record_type: self.record_type,
+            timestamp: self.timestamp,
+            id: self.id,
+            node: self.node.map(|node| Cow::Owned(node.into_owned())),
+            fields: self
+                .fields
+                .into_iter()
+                .map(|(key, value)| (Cow::Owned(key.into_owned()), value.into_owned()))
+                .collect(),
+            enrichment: self.enrichment.map(|enrichment| {
+                enrichment
+                    .into_iter()
+                    .map(|(key, value)| {
+                        (Cow::Owned(key.into_owned()), Cow::Owned(value.into_owned()))
+                    })
+                    .collect()
+            }),
+            unparsed: self
+                .unparsed
+                .into_iter()
+                .map(|segment| Cow::Owned(segment.into_owned()))
+                .collect(),
+            warnings: self.warnings,
+            invalid_utf8: self.invalid_utf8,
+        }
+    }
 }
 
-#[derive(Debug, Error)]
-#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
-pub enum ParserError {
-    #[error("parsing error: {0}")]
-    Parse(String),
+/// Options to customize how records are parsed.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct ParseOptions {
+    /// Skips the parts of the body that can not be parsed instead of failing, collecting them
+    /// into the `unparsed` segments and `warnings` of the record. Malformed headers still fail.
+    pub lenient: bool,
+    /// How the fields are interpreted when parsing an [`AuditdRecord`](crate::AuditdRecord),
+    /// which is not used by [`RawAuditdRecord`].
+    pub interpret: InterpretOptions,
 }
 
-pub fn parse_record(input: &str) -> Result<RawAuditdRecord, ParserError> {
-    (parse_header, parse_body)
-        .map(|(header, body)| RawAuditdRecord {
-            record_type: header.record_type,
-            timestamp: header.audit_msg.timestamp,
-            id: header.audit_msg.id,
-            node: header.node,
-            fields: body.fields,
-            enrichment: body.enrichment,
-        })
-        .parse(input)
+pub fn parse_record(input: &str) -> Result<RawAuditdRecord<'_>, ParserError> {
+    parse_record_with(input, &ParseOptions::default())
+}
+
+pub fn parse_record_with<'a>(
+    input: &'a str,
+    options: &ParseOptions,
+) -> Result<RawAuditdRecord<'a>, ParserError> {
+    let (body_input, header) = parse_header(input)
         .finish()
-        .map(|(_, record)| record)
-        .map_err(|err| ParserError::Parse(err.to_string()))
+        .map_err(|err| ParserError::from_header(input, err.input))?;
+
+    let (body, skipped) = match parse_body(body_input, &header.record_type).finish() {
+        Ok((_, body)) => (body, Vec::new()),
+        Err(_) if options.lenient => {
+            let LenientBody { body, skipped } = parse_lenient_body(body_input, &header.record_type);
+            (body, skipped)
+        }
+        Err(_) => {
+            // The error of `parse_body` points to the end of the shortest alternative,
+            // so parse as much as possible to find the data that could not be parsed
+            let remaining = parse_partial_body(body_input, &header.record_type)
+                .map_or(body_input, |(remaining, _)| remaining);
+            return Err(ParserError::from_body(input, remaining));
+        }
+    };
+
+    let (unparsed, warnings) = skipped
+        .into_iter()
+        .map(|(segment, remaining)| {
+            (
+                Cow::Borrowed(segment),
+                ParserError::from_body(input, remaining),
+            )
+        })
+        .unzip();
+
+    Ok(RawAuditdRecord {
+        record_type: header.record_type,
+        timestamp: header
+            .audit_msg
+            .as_ref()
+            .map(|audit_msg| audit_msg.timestamp),
+        id: header.audit_msg.map(|audit_msg| audit_msg.id),
+        node: header.node.map(Cow::Borrowed),
+        fields: body.fields,
+        enrichment: body.enrichment,
+        unparsed,
+        warnings,
+        invalid_utf8: false,
+    })
+}
+
+pub fn parse_record_bytes<'a>(
+    input: &'a [u8],
+    options: &ParseOptions,
+) -> Result<RawAuditdRecord<'a>, ParserError> {
+    match escape_invalid_utf8(input) {
+        Cow::Borrowed(input) => parse_record_with(input, options),
+        Cow::Owned(escaped) => match parse_record_with(&escaped, options) {
+            Ok(record) => Ok(RawAuditdRecord {
+                invalid_utf8: true,
+                ..record.into_owned()
+            }),
+            Err(err) => Err(err.unescape(&escaped)),
+        },
+    }
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
     use body::ENRICHMENT_SEPARATOR;
-    use maplit::btreemap;
     use rstest::rstest;
 
     #[rstest]
     #[case::not_enriched("type=foo msg=audit(1234.567:89): key1=value1 key2=value2",
         RawAuditdRecord {
             record_type: "foo".into(),
-            timestamp: 1_234_567,
-            id: 89,
+            timestamp: Some(AuditTimestamp::from_millis(1_234_567)),
+            id: Some(89),
             node: None,
-            fields: btreemap!{"key1".into() => "value1".into(), "key2".into() => "value2".into()},
+            fields: Fields::from([("key1", "value1".into()), ("key2", "value2".into())]),
             enrichment: None,
+            unparsed: Vec::new(),
+            warnings: Vec::new(),
+            invalid_utf8: false,
         }
     )]
     #[case::enriched(&format!("type=foo msg=audit(1234.567:89): key1=value1 key2=value2{ENRICHMENT_SEPARATOR}enriched_key=enriched_value"),
         RawAuditdRecord {
             record_type: "foo".into(),
-            timestamp: 1_234_567,
-            id: 89,
+            timestamp: Some(AuditTimestamp::from_millis(1_234_567)),
+            id: Some(89),
             node: None,
-            fields: btreemap!{"key1".into() => "value1".into(), "key2".into() => "value2".into()},
-            enrichment: Some(btreemap!{"enriched_key".into() => "enriched_value".into()}),
+            fields: Fields::from([("key1", "value1".into()), ("key2", "value2".into())]),
+            enrichment: Some(Fields::from([("enriched_key", "enriched_value".into())])),
+            unparsed: Vec::new(),
+            warnings: Vec::new(),
+            invalid_utf8: false,
         }
     )]
     #[case::with_node("node=server.example.com type=foo msg=audit(1234.567:89): key1=value1 key2=value2",
         RawAuditdRecord {
             record_type: "foo".into(),
-            timestamp: 1_234_567,
-            id: 89,
+            timestamp: Some(AuditTimestamp::from_millis(1_234_567)),
+            id: Some(89),
             node: Some("server.example.com".into()),
-            fields: btreemap!{"key1".into() => "value1".into(), "key2".into() => "value2".into()},
+            fields: Fields::from([("key1", "value1".into()), ("key2", "value2".into())]),
+            enrichment: None,
+            unparsed: Vec::new(),
+            warnings: Vec::new(),
+            invalid_utf8: false,
+        }
+    )]
+    #[case::quoted_and_unquoted_values("type=PATH msg=audit(1234.567:89): name=\"cafe\" comm=63616665",
+        RawAuditdRecord {
+            record_type: RecordType::Path,
+            timestamp: Some(AuditTimestamp::from_millis(1_234_567)),
+            id: Some(89),
+            node: None,
+            fields: Fields::from([
+                ("name", RawValue::Quoted("cafe".into())),
+                ("comm", RawValue::Unquoted("63616665".into())),
+            ]),
+            enrichment: None,
+            unparsed: Vec::new(),
+            warnings: Vec::new(),
+            invalid_utf8: false,
+        }
+    )]
+    #[case::duplicated_keys("type=foo msg=audit(1234.567:89): subj=first pid=1 subj=second",
+        RawAuditdRecord {
+            record_type: "foo".into(),
+            timestamp: Some(AuditTimestamp::from_millis(1_234_567)),
+            id: Some(89),
+            node: None,
+            fields: Fields::from([
+                ("subj", "first".into()),
+                ("pid", "1".into()),
+                ("subj", "second".into()),
+            ]),
+            enrichment: None,
+            unparsed: Vec::new(),
+            warnings: Vec::new(),
+            invalid_utf8: false,
+        }
+    )]
+    #[case::trailing_backslash(r#"type=SYSCALL msg=audit(1234.567:89): comm="foo\" exe="/usr/bin/foo""#,
+        RawAuditdRecord {
+            record_type: "SYSCALL".into(),
+            timestamp: Some(AuditTimestamp::from_millis(1_234_567)),
+            id: Some(89),
+            node: None,
+            fields: Fields::from([
+                ("comm", RawValue::Quoted(r"foo\".into())),
+                ("exe", RawValue::Quoted("/usr/bin/foo".into())),
+            ]),
+            enrichment: None,
+            unparsed: Vec::new(),
+            warnings: Vec::new(),
+            invalid_utf8: false,
+        }
+    )]
+    #[case::backslashes(r#"type=PATH msg=audit(1234.567:89): name="a\\b" nametype=NORMAL"#,
+        RawAuditdRecord {
+            record_type: RecordType::Path,
+            timestamp: Some(AuditTimestamp::from_millis(1_234_567)),
+            id: Some(89),
+            node: None,
+            fields: Fields::from([
+                ("name", RawValue::Quoted(r"a\\b".into())),
+                ("nametype", "NORMAL".into()),
+            ]),
+            enrichment: None,
+            unparsed: Vec::new(),
+            warnings: Vec::new(),
+            invalid_utf8: false,
+        }
+    )]
+    #[case::empty_body("type=EOE msg=audit(1234.567:89):",
+        RawAuditdRecord {
+            record_type: "EOE".into(),
+            timestamp: Some(AuditTimestamp::from_millis(1_234_567)),
+            id: Some(89),
+            node: None,
+            fields: Fields::new(),
+            enrichment: None,
+            unparsed: Vec::new(),
+            warnings: Vec::new(),
+            invalid_utf8: false,
+        }
+    )]
+    #[case::unknown_audit_msg("type=UNKNOWN[1329] msg=?",
+        RawAuditdRecord {
+            record_type: "UNKNOWN[1329]".into(),
+            timestamp: None,
+            id: None,
+            node: None,
+            fields: Fields::new(),
             enrichment: None,
+            unparsed: Vec::new(),
+            warnings: Vec::new(),
+            invalid_utf8: false,
         }
     )]
     fn test_parse_record(#[case] input: &str, #[case] expected: RawAuditdRecord) {
@@ -97,11 +352,154 @@ mod tests {
     }
 
     #[rstest]
-    #[case::invalid_header("foo msg=audit(1234.567:89) key1=value1")]
-    #[case::invalid_body("type=foo msg=audit(1234.567:89): bar")]
-    #[case::trailing_data("type=foo msg=audit(1234.567:89): key1=value1 key2=value2 foo")]
-    #[case::empty("")]
-    fn test_parse_record_fails(#[case] input: &str) {
-        assert!(parse_record(input).is_err());
+    #[case::invalid_header(
+        "foo msg=audit(1234.567:89) key1=value1",
+        ParserError::InvalidHeader { offset: 0, excerpt: "foo msg=audit(1234.567:89) key1=".into() }
+    )]
+    #[case::invalid_audit_msg(
+        "type=foo msg=foo",
+        ParserError::InvalidHeader { offset: 13, excerpt: "foo".into() }
+    )]
+    #[case::invalid_timestamp(
+        "type=foo msg=audit(1234:89): key1=value1",
+        ParserError::InvalidTimestamp { offset: 23, excerpt: ":89): key1=value1".into() }
+    )]
+    #[case::invalid_id(
+        "type=foo msg=audit(1234.567:foo): key1=value1",
+        ParserError::InvalidTimestamp { offset: 28, excerpt: "foo): key1=value1".into() }
+    )]
+    #[case::invalid_body(
+        "type=foo msg=audit(1234.567:89): bar",
+        ParserError::InvalidKey { offset: 33, excerpt: "bar".into() }
+    )]
+    #[case::invalid_key(
+        "type=foo msg=audit(1234.567:89): key1=value1 =value2",
+        ParserError::InvalidKey { offset: 45, excerpt: "=value2".into() }
+    )]
+    #[case::unterminated_quote(
+        "type=foo msg=audit(1234.567:89): key1=value1 \"foo bar",
+        ParserError::UnterminatedQuote { offset: 45, excerpt: "\"foo bar".into() }
+    )]
+    #[case::unterminated_quoted_value(
+        "type=foo msg=audit(1234.567:89): key1=\"foo",
+        ParserError::UnterminatedQuote { offset: 33, excerpt: "key1=\"foo".into() }
+    )]
+    #[case::unterminated_quoted_value_with_space(
+        "type=foo msg=audit(1234.567:89): key1=\"foo bar",
+        ParserError::UnterminatedQuote { offset: 33, excerpt: "key1=\"foo bar".into() }
+    )]
+    #[case::trailing_data(
+        "type=foo msg=audit(1234.567:89): key1=value1 key2=value2 foo",
+        ParserError::InvalidKey { offset: 57, excerpt: "foo".into() }
+    )]
+    #[case::data_after_quoted_value(
+        "type=foo msg=audit(1234.567:89): key1=\"value1\"foo",
+        ParserError::TrailingData { offset: 46, excerpt: "foo".into() }
+    )]
+    #[case::empty(
+        "",
+        ParserError::InvalidHeader { offset: 0, excerpt: String::new() }
+    )]
+    fn test_parse_record_fails(#[case] input: &str, #[case] expected: ParserError) {
+        assert_eq!(parse_record(input).unwrap_err(), expected);
+    }
+
+    #[rstest]
+    #[case::valid(
+        "type=foo msg=audit(1234.567:89): key1=value1",
+        Fields::from([("key1", "value1".into())]),
+        vec![]
+    )]
+    #[case::stray_word(
+        "type=foo msg=audit(1234.567:89): key1=value1 foo key2=value2",
+        Fields::from([("key1", "value1".into()), ("key2", "value2".into())]),
+        vec![("foo", ParserError::InvalidKey { offset: 45, excerpt: "foo key2=value2".into() })]
+    )]
+    #[case::unterminated_quote(
+        "type=foo msg=audit(1234.567:89): key1=value1 \"foo bar",
+        Fields::from([("key1", "value1".into())]),
+        vec![("\"foo bar", ParserError::UnterminatedQuote { offset: 45, excerpt: "\"foo bar".into() })]
+    )]
+    #[case::data_after_quoted_value(
+        "type=foo msg=audit(1234.567:89): key1=\"value1\"foo key2=value2",
+        Fields::from([("key1", RawValue::Quoted("value1".into())), ("key2", "value2".into())]),
+        vec![("foo", ParserError::TrailingData { offset: 46, excerpt: "foo key2=value2".into() })]
+    )]
+    fn test_parse_record_lenient(
+        #[case] input: &str,
+        #[case] expected_fields: RawFields<'_>,
+        #[case] expected_unparsed: Vec<(&str, ParserError)>,
+    ) {
+        let options = ParseOptions {
+            lenient: true,
+            ..Default::default()
+        };
+        let record = parse_record_with(input, &options).unwrap();
+        let (unparsed, warnings): (Vec<_>, Vec<_>) = expected_unparsed
+            .into_iter()
+            .map(|(segment, warning)| (Cow::Borrowed(segment), warning))
+            .unzip();
+        assert_eq!(record.fields, expected_fields);
+        assert_eq!(record.unparsed, unparsed);
+        assert_eq!(record.warnings, warnings);
+    }
+
+    #[test]
+    fn test_parse_record_lenient_invalid_header_fails() {
+        let options = ParseOptions {
+            lenient: true,
+            ..Default::default()
+        };
+        assert!(parse_record_with("foo msg=audit(1234.567:89): key1=value1", &options).is_err());
+    }
+
+    #[test]
+    fn test_into_owned_outlives_input() {
+        let record = {
+            let input = String::from("node=foo type=foo msg=audit(1234.567:89): key1=\"value1\"");
+            parse_record(&input).unwrap().into_owned()
+        };
+        assert_eq!(record.node, Some("foo".into()));
+        assert_eq!(
+            record.fields,
+            Fields::from([("key1", RawValue::Quoted("value1".into()))])
+        );
+    }
+
+    #[test]
+    fn test_parse_record_bytes_keeps_invalid_utf8() {
+        let input = b"type=foo msg=audit(1234.567:89): comm=\"c\xffat\" key=value";
+        let record = parse_record_bytes(input, &ParseOptions::default()).unwrap();
+        assert!(record.invalid_utf8);
+        assert_eq!(record.field_bytes("comm").unwrap(), &b"c\xffat"[..]);
+        assert_eq!(record.fields.get("key"), Some(&"value".into()));
+    }
+
+    #[test]
+    fn test_parse_record_bytes_keeps_escape_code_points_of_valid_lines() {
+        let input = "type=foo msg=audit(1234.567:89): comm=\"a\u{10FFFF}b\"";
+        let record = parse_record_bytes(input.as_bytes(), &ParseOptions::default()).unwrap();
+        assert!(!record.invalid_utf8);
+        assert_eq!(
+            record.field_bytes("comm").unwrap(),
+            "a\u{10FFFF}b".as_bytes()
+        );
+    }
+
+    #[rstest]
+    #[case::valid(b"type=foo msg=audit(1234.567:89): key1=value1 bar", ParserError::InvalidKey { offset: 45, excerpt: "bar".into() })]
+    #[case::invalid_utf8_before_error(
+        b"type=foo msg=audit(1234.567:89): key1=\xff\xfe bar",
+        ParserError::InvalidKey { offset: 41, excerpt: "bar".into() }
+    )]
+    #[case::invalid_utf8_in_excerpt(
+        b"type=foo msg=audit(1234.567:89): key1=value1 b\xffr",
+        ParserError::InvalidKey { offset: 45, excerpt: "b\u{fffd}r".into() }
+    )]
+    fn test_parse_record_bytes_fails(#[case] input: &[u8], #[case] expected: ParserError) {
+        assert_eq!(
+            parse_record_bytes(input, &ParseOptions::default()).unwrap_err(),
+            expected
+        );
     }
 }
@@ -1,43 +1,122 @@
+use std::borrow::Cow;
+
+use avc::parse_avc_prefix;
+use enrichment::parse_enrichment;
 use key::parse_key;
 use nom::branch::alt;
+use nom::bytes::complete::tag;
 use nom::character::complete::char;
 use nom::character::complete::space0;
 use nom::character::complete::space1;
-use nom::combinator::all_consuming;
+use nom::combinator::{all_consuming, opt};
 use nom::multi::separated_list1;
-use nom::sequence::{preceded, separated_pair};
+use nom::sequence::{preceded, separated_pair, terminated};
 use nom::{IResult, Parser};
-use std::collections::BTreeMap;
+use pam::parse_legacy_pam_message;
+use text::parse_text_prefix;
 use value::parse_value;
 
+use crate::{Fields, RecordType};
+
+mod avc;
+mod enrichment;
 mod key;
+mod pam;
+mod text;
 mod value;
 
+pub use avc::{AVC_DECISION_KEY, AVC_PERMISSIONS_KEY};
+pub use enrichment::parse_braced_fields;
+pub use value::RawValue;
+
 pub const ENRICHMENT_SEPARATOR: char = '\x1d';
 
+/// Fields whose keys and values are borrowed from the parsed input.
+pub type RawFields<'a> = Fields<RawValue<'a>, Cow<'a, str>>;
+
 #[derive(Debug, PartialEq, Eq)]
-pub struct InnerBody {
-    pub fields: BTreeMap<String, String>,
-    pub enrichment: Option<BTreeMap<String, String>>,
+pub struct InnerBody<'a> {
+    pub fields: RawFields<'a>,
+    pub enrichment: Option<Fields<Cow<'a, str>, Cow<'a, str>>>,
 }
 
 /// Parses a key-value pair
-fn parse_key_value(input: &str) -> IResult<&str, (String, String)> {
-    separated_pair(parse_key, char('='), parse_value).parse(input)
+fn parse_key_value(input: &str) -> IResult<&str, (Cow<'_, str>, RawValue<'_>)> {
+    let (input, key) = terminated(parse_key, char('=')).parse(input)?;
+    let (input, value) = parse_value(input, &key)?;
+    Ok((input, (key, value)))
 }
 
 /// Parses a list of key-value pairs, separated by spaces
-pub fn parse_key_value_list(input: &str) -> IResult<&str, BTreeMap<String, String>> {
+pub fn parse_key_value_list(input: &str) -> IResult<&str, RawFields<'_>> {
     preceded(space0, separated_list1(space1, parse_key_value))
-        .map(BTreeMap::from_iter)
+        .map(Fields::from_iter)
         .parse(input)
 }
 
-fn parse_enriched_body(input: &str) -> IResult<&str, InnerBody> {
-    separated_pair(
+/// Parses the `user ` bareword that precedes the fields of legacy userspace records.
+/// It does not carry any information, so no key-value pair is returned.
+///
+/// Example: `user pid=13015 uid=0 auid=0 msg='...'`
+fn parse_legacy_user_prefix(input: &str) -> IResult<&str, Vec<(&'static str, &str)>> {
+    terminated(tag("user"), space1).map(|_| vec![]).parse(input)
+}
+
+/// Whether records of the given type may have free-form text before their key-value pairs,
+/// as the daemon (`DAEMON_*`) and MAC (`MAC_*`) records do, or the `tty` bareword of
+/// kernel `TTY` records.
+fn has_text_prefix(record_type: &RecordType) -> bool {
+    *record_type == RecordType::Tty
+        || record_type
+            .name()
+            .is_some_and(|name| name.starts_with("DAEMON_") || name.starts_with("MAC_"))
+}
+
+/// Parses the free-form prefix that some records have before their key-value pairs.
+/// Known prefixes are tried first, falling back to capture any leading text
+/// for the record types that have it.
+fn parse_prefix<'a>(
+    input: &'a str,
+    record_type: &RecordType,
+) -> IResult<&'a str, Vec<(&'static str, &'a str)>> {
+    if has_text_prefix(record_type) {
+        alt((
+            parse_avc_prefix,
+            parse_legacy_user_prefix,
+            parse_text_prefix,
+        ))
+        .parse(input)
+    } else {
+        alt((parse_avc_prefix, parse_legacy_user_prefix)).parse(input)
+    }
+}
+
+/// Parses the fields of the record, which are a list of key-value pairs optionally
+/// preceded by a free-form prefix (such as the one from `SELinux` AVC records).
+fn parse_fields<'a>(input: &'a str, record_type: &RecordType) -> IResult<&'a str, RawFields<'a>> {
+    (
+        opt(|input| parse_prefix(input, record_type)),
         parse_key_value_list,
+    )
+        .map(|(prefix_fields, fields)| {
+            prefix_fields
+                .into_iter()
+                .flatten()
+                .map(|(key, value)| (Cow::Borrowed(key), RawValue::Unquoted(Cow::Borrowed(value))))
+                .chain(fields)
+                .collect()
+        })
+        .parse(input)
+}
+
+fn parse_enriched_body<'a>(
+    input: &'a str,
+    record_type: &RecordType,
+) -> IResult<&'a str, InnerBody<'a>> {
+    separated_pair(
+        |input| parse_fields(input, record_type),
         char(ENRICHMENT_SEPARATOR),
-        parse_key_value_list,
+        parse_enrichment,
     )
     .map(|(fields, enrichment)| InnerBody {
         fields,
@@ -46,8 +125,11 @@ fn parse_enriched_body(input: &str) -> IResult<&str, InnerBody> {
     .parse(input)
 }
 
-fn parse_not_enriched_body(input: &str) -> IResult<&str, InnerBody> {
-    parse_key_value_list
+fn parse_not_enriched_body<'a>(
+    input: &'a str,
+    record_type: &RecordType,
+) -> IResult<&'a str, InnerBody<'a>> {
+    (|input| parse_fields(input, record_type))
         .map(|fields| InnerBody {
             fields,
             enrichment: None,
@@ -55,30 +137,144 @@ fn parse_not_enriched_body(input: &str) -> IResult<&str, InnerBody> {
         .parse(input)
 }
 
-pub fn parse_body(input: &str) -> IResult<&str, InnerBody> {
-    all_consuming(alt((parse_enriched_body, parse_not_enriched_body))).parse(input)
+/// Parses a body without fields, as the one from `EOE` records.
+fn parse_empty_body(input: &str) -> IResult<&str, InnerBody<'_>> {
+    space0
+        .map(|_| InnerBody {
+            fields: Fields::new(),
+            enrichment: None,
+        })
+        .parse(input)
+}
+
+/// Parses as much of the body as possible, leaving the data that could not be parsed
+/// as the remaining input. It is used to locate the data that made [`parse_body`] fail.
+pub fn parse_partial_body<'a>(
+    input: &'a str,
+    record_type: &RecordType,
+) -> IResult<&'a str, InnerBody<'a>> {
+    alt((
+        |input| parse_enriched_body(input, record_type),
+        |input| parse_not_enriched_body(input, record_type),
+        parse_empty_body,
+    ))
+    .parse(input)
+}
+
+pub fn parse_body<'a>(input: &'a str, record_type: &RecordType) -> IResult<&'a str, InnerBody<'a>> {
+    all_consuming(|input| parse_partial_body(input, record_type)).parse(input)
+}
+
+/// Body parsed by [`parse_lenient_body`], along with the data that was skipped.
+#[derive(Debug, PartialEq, Eq)]
+pub struct LenientBody<'a> {
+    pub body: InnerBody<'a>,
+    /// Skipped segments, along with the input remaining at each of them
+    pub skipped: Vec<(&'a str, &'a str)>,
+}
+
+/// Returns the segment at the start of data that could not be parsed: an unterminated
+/// quoted string spans the rest of the fields, while anything else ends at the next space.
+/// Segments never span the enrichment separator, so the enrichment can still be parsed.
+fn unparsed_segment(input: &str) -> &str {
+    let fields_end = input.find(ENRICHMENT_SEPARATOR).unwrap_or(input.len());
+    if input.starts_with(['"', '\'']) && !input[1..fields_end].contains(&input[..1]) {
+        return &input[..fields_end];
+    }
+
+    let end = input
+        .find(|c: char| c.is_whitespace() || c == ENRICHMENT_SEPARATOR)
+        .unwrap_or(input.len());
+    &input[..end]
+}
+
+/// Parses the body, skipping the segments that can not be parsed instead of failing,
+/// so that the rest of the fields are still returned.
+pub fn parse_lenient_body<'a>(input: &'a str, record_type: &RecordType) -> LenientBody<'a> {
+    let mut skipped = Vec::new();
+    let mut fields = Vec::new();
+    let mut enrichment_input = None;
+    let mut remaining = input;
+    let mut first = true;
+    loop {
+        // The free-form prefix may only be found before the first key-value pair
+        let parsed = if first {
+            parse_fields(remaining, record_type)
+        } else {
+            parse_key_value_list(remaining)
+        };
+        first = false;
+        if let Ok((rest, parsed)) = parsed {
+            fields.extend(parsed);
+            remaining = rest;
+        }
+
+        remaining = remaining.trim_start();
+        if remaining.is_empty() {
+            break;
+        }
+
+        // The separator is only found here when it is not part of a quoted value
+        if let Some(rest) = remaining.strip_prefix(ENRICHMENT_SEPARATOR) {
+            enrichment_input = Some(rest);
+            break;
+        }
+
+        let segment = unparsed_segment(remaining);
+        skipped.push((segment, remaining));
+        remaining = &remaining[segment.len()..];
+    }
+
+    let enrichment = enrichment_input.and_then(|enrichment_input| {
+        if let Ok((_, enrichment)) = all_consuming(parse_enrichment).parse(enrichment_input) {
+            Some(enrichment)
+        } else {
+            let enrichment_input = enrichment_input.trim_start();
+            if !enrichment_input.is_empty() {
+                skipped.push((enrichment_input, enrichment_input));
+            }
+            None
+        }
+    });
+
+    LenientBody {
+        body: InnerBody {
+            fields: Fields::from_iter(fields),
+            enrichment,
+        },
+        skipped,
+    }
+}
+
+/// Parses the content of userspace `msg='...'` fields, which is usually a list of
+/// key-value pairs, but may also be a legacy PAM message.
+pub fn parse_msg_fields(input: &str) -> IResult<&str, RawFields<'_>> {
+    alt((
+        all_consuming(parse_key_value_list),
+        all_consuming(parse_legacy_pam_message).map(Fields::from_iter),
+    ))
+    .parse(input)
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
-    use maplit::btreemap;
     use rstest::rstest;
 
     #[rstest]
     #[case::regular("key=value", ("key", "value"))]
+    #[case::missing_value("key=", ("key", ""))]
     fn test_parse_key_value(#[case] input: &str, #[case] expected: (&str, &str)) {
         let (expected_key, expected_value) = expected;
         let (remaining, (key, value)) = parse_key_value(input).unwrap();
         assert!(remaining.is_empty());
         assert_eq!(key, expected_key);
-        assert_eq!(value, expected_value);
+        assert_eq!(value, expected_value.into());
     }
 
     #[rstest]
     #[case::missing_separator("keyvalue")]
     #[case::missing_key("=value")]
-    #[case::missing_value("key=")]
     #[case::missing_key_and_value("=")]
     #[case::empty("")]
     fn test_parse_key_value_fails(#[case] input: &str) {
@@ -86,22 +282,34 @@ mod tests {
     }
 
     #[rstest]
-    #[case::single("key1=value1", btreemap!{"key1".into() => "value1".into()})]
+    #[case::single("key1=value1", Fields::from([("key1", "value1".into())]))]
     #[case::multiple("key1=value1 key2=value2 key3=value3",
-        btreemap!{"key1".into() => "value1".into(),
-            "key2".into() => "value2".into(),"key3".into() => "value3".into()}
+        Fields::from([
+            ("key1", "value1".into()),
+            ("key2", "value2".into()),
+            ("key3", "value3".into()),
+        ])
     )]
     #[case::preceding_space(" key1=value1",
-        btreemap!{"key1".into() => "value1".into()}
+        Fields::from([("key1", "value1".into())])
     )]
     #[case::multiple_preceding_space("  key1=value1",
-        btreemap!{"key1".into() => "value1".into()}
+        Fields::from([("key1", "value1".into())])
     )]
     #[case::multiple_space_separator("key1=value1   key2=value2   key3=value3",
-        btreemap!{"key1".into() => "value1".into(),
-            "key2".into() => "value2".into(),"key3".into() => "value3".into()}
+        Fields::from([
+            ("key1", "value1".into()),
+            ("key2", "value2".into()),
+            ("key3", "value3".into()),
+        ])
     )]
-    fn test_parse_key_value_list(#[case] input: &str, #[case] expected: BTreeMap<String, String>) {
+    #[case::missing_value("key1= key2=value2",
+        Fields::from([("key1", "".into()), ("key2", "value2".into())])
+    )]
+    #[case::apparmor_label("subj=/usr/sbin/ntpd (enforce) key2=value2",
+        Fields::from([("subj", "/usr/sbin/ntpd (enforce)".into()), ("key2", "value2".into())])
+    )]
+    fn test_parse_key_value_list(#[case] input: &str, #[case] expected: RawFields<'_>) {
         let (remaining, result) = parse_key_value_list(input).unwrap();
         assert!(remaining.is_empty());
         assert_eq!(result, expected);
@@ -109,7 +317,6 @@ mod tests {
 
     #[rstest]
     #[case::missing_key("=value1 key2=value2")]
-    #[case::missing_value("key1= key2=value2")]
     #[case::missing_key_and_value("=")]
     #[case::missing_equal("foo")]
     #[case::empty("")]
@@ -118,15 +325,85 @@ mod tests {
         assert!(parse_key_value_list(input).is_err());
     }
 
+    #[rstest]
+    #[case::key_value_list("key1=value1 key2=value2",
+        Fields::from([("key1", "value1".into()), ("key2", "value2".into())])
+    )]
+    #[case::avc_prefix("avc:  denied  { read write } for  pid=1 tclass=dir",
+        Fields::from([
+            ("avc_decision", "denied".into()),
+            ("permissions", "read write".into()),
+            ("pid", "1".into()),
+            ("tclass", "dir".into()),
+        ])
+    )]
+    #[case::legacy_user_prefix("user pid=1 uid=0",
+        Fields::from([("pid", "1".into()), ("uid", "0".into())])
+    )]
+    #[case::user_key("user=foo uid=0",
+        Fields::from([("user", "foo".into()), ("uid", "0".into())])
+    )]
+    #[case::text_prefix("auditd start, ver=2.4.1 res=success",
+        Fields::from([
+            ("text", "auditd start".into()),
+            ("ver", "2.4.1".into()),
+            ("res", "success".into()),
+        ])
+    )]
+    fn test_parse_fields(#[case] input: &str, #[case] expected: RawFields<'_>) {
+        let (remaining, result) = parse_fields(input, &RecordType::DaemonStart).unwrap();
+        assert!(remaining.is_empty());
+        assert_eq!(result, expected);
+    }
+
+    #[rstest]
+    #[case::avc_prefix_without_fields("avc:  denied  { read write } for  ")]
+    #[case::legacy_user_prefix_without_fields("user ")]
+    #[case::text_prefix_without_fields("auditd start, ")]
+    #[case::invalid_key_value("foo")]
+    #[case::empty("")]
+    fn test_parse_fields_fails(#[case] input: &str) {
+        assert!(parse_fields(input, &RecordType::DaemonStart).is_err());
+    }
+
+    #[rstest]
+    #[case::daemon(RecordType::DaemonStart)]
+    #[case::mac(RecordType::MacPolicyLoad)]
+    #[case::tty(RecordType::Tty)]
+    fn test_parse_fields_text_prefix(#[case] record_type: RecordType) {
+        let (_, result) = parse_fields("policy loaded auid=0", &record_type).unwrap();
+        assert_eq!(
+            result,
+            Fields::from([("text", "policy loaded".into()), ("auid", "0".into())])
+        );
+    }
+
+    #[rstest]
+    #[case::syscall(RecordType::Syscall)]
+    #[case::unknown(RecordType::Unknown(9999))]
+    fn test_parse_fields_text_prefix_fails(#[case] record_type: RecordType) {
+        assert!(parse_fields("policy loaded auid=0", &record_type).is_err());
+    }
+
     #[rstest]
     #[case::regular(&format!("key1=value1 key2=value2{ENRICHMENT_SEPARATOR}enriched_key=enriched_value"),
         InnerBody{
-            fields: btreemap!{"key1".into() => "value1".into(), "key2".into() => "value2".into()},
-            enrichment: Some(btreemap!{"enriched_key".into() => "enriched_value".into()})
+            fields: Fields::from([("key1", "value1".into()), ("key2", "value2".into())]),
+            enrichment: Some(Fields::from([("enriched_key", "enriched_value".into())]))
+        }
+    )]
+    #[case::braced_and_multi_word_enrichment(
+        &format!("saddr=0100{ENRICHMENT_SEPARATOR}SADDR={{ fam=local path=/tmp/{{foo}} }} OTHER=unknown family(0)"),
+        InnerBody{
+            fields: Fields::from([("saddr", "0100".into())]),
+            enrichment: Some(Fields::from([
+                ("SADDR", "{ fam=local path=/tmp/{foo} }".into()),
+                ("OTHER", "unknown family(0)".into()),
+            ]))
         }
     )]
     fn test_parse_enriched_body(#[case] input: &str, #[case] expected: InnerBody) {
-        let (remaining, result) = parse_body(input).unwrap();
+        let (remaining, result) = parse_body(input, &RecordType::Syscall).unwrap();
         assert!(remaining.is_empty());
         assert_eq!(result, expected);
     }
@@ -136,56 +413,100 @@ mod tests {
     #[case::empty_fields(&format!("{ENRICHMENT_SEPARATOR}enriched_key=enriched_value"))]
     #[case::empty_enrichment_and_fields(&format!("{ENRICHMENT_SEPARATOR}"))]
     #[case::invalid_key_value("foo")]
-    #[case::empty("")]
     fn test_parse_enriched_body_fails(#[case] input: &str) {
-        assert!(parse_body(input).is_err());
+        assert!(parse_body(input, &RecordType::Syscall).is_err());
     }
 
     #[rstest]
     #[case::regular("key1=value1 key2=value2",
         InnerBody{
-            fields: btreemap!{"key1".into() => "value1".into(), "key2".into() => "value2".into()},
+            fields: Fields::from([("key1", "value1".into()), ("key2", "value2".into())]),
             enrichment: None
         }
     )]
     fn test_parse_unenriched_body(#[case] input: &str, #[case] expected: InnerBody) {
-        let (remaining, result) = parse_body(input).unwrap();
+        let (remaining, result) = parse_body(input, &RecordType::Syscall).unwrap();
         assert!(remaining.is_empty());
         assert_eq!(result, expected);
     }
 
     #[rstest]
     #[case::invalid_key_value("foo")]
-    #[case::empty("")]
     fn test_parse_unenriched_body_fails(#[case] input: &str) {
-        assert!(parse_body(input).is_err());
+        assert!(parse_body(input, &RecordType::Syscall).is_err());
     }
 
     #[rstest]
     #[case::enriched(&format!("key1=value1 key2=value2{ENRICHMENT_SEPARATOR}enriched_key=enriched_value"),
         InnerBody{
-            fields: btreemap!{"key1".into() => "value1".into(), "key2".into() => "value2".into()},
-            enrichment: Some(btreemap!{"enriched_key".into() => "enriched_value".into()})
+            fields: Fields::from([("key1", "value1".into()), ("key2", "value2".into())]),
+            enrichment: Some(Fields::from([("enriched_key", "enriched_value".into())]))
         }
     )]
     #[case::not_enriched("key1=value1 key2=value2",
         InnerBody{
-            fields: btreemap!{"key1".into() => "value1".into(), "key2".into() => "value2".into()},
+            fields: Fields::from([("key1", "value1".into()), ("key2", "value2".into())]),
+            enrichment: None
+        }
+    )]
+    #[case::missing_value(&format!("key1= key2=value2{ENRICHMENT_SEPARATOR}enriched_key=enriched_value"),
+        InnerBody{
+            fields: Fields::from([("key1", "".into()), ("key2", "value2".into())]),
+            enrichment: Some(Fields::from([("enriched_key", "enriched_value".into())]))
+        }
+    )]
+    #[case::empty("",
+        InnerBody{
+            fields: Fields::new(),
+            enrichment: None
+        }
+    )]
+    #[case::only_spaces("  ",
+        InnerBody{
+            fields: Fields::new(),
             enrichment: None
         }
     )]
     fn test_parse_body(#[case] input: &str, #[case] expected: InnerBody) {
-        let (remaining, result) = parse_body(input).unwrap();
+        let (remaining, result) = parse_body(input, &RecordType::Syscall).unwrap();
         assert!(remaining.is_empty());
         assert_eq!(result, expected);
     }
 
     #[rstest]
     #[case::trailing_data("key1=value1 key2=value2 foo")]
+    #[case::parenthesis_outside_label("key=foo (bar)")]
     #[case::invalid_key_value("foo")]
-    #[case::empty("")]
+    #[case::only_enrichment_separator(&format!("{ENRICHMENT_SEPARATOR}"))]
     fn test_parse_body_fails(#[case] input: &str) {
-        assert!(dbg!(parse_body(input)).is_err());
+        assert!(dbg!(parse_body(input, &RecordType::Syscall)).is_err());
+    }
+
+    #[rstest]
+    #[case::key_value_list("op=PAM:accounting acct=\"root\"",
+        Fields::from([("op", "PAM:accounting".into()), ("acct", RawValue::Quoted("root".into()))])
+    )]
+    #[case::legacy_pam("PAM: accounting acct=root : exe=\"/usr/sbin/crond\" (hostname=?, res=success)",
+        Fields::from([
+            ("op", "PAM: accounting".into()),
+            ("acct", "root".into()),
+            ("exe", RawValue::Quoted("/usr/sbin/crond".into())),
+            ("hostname", "?".into()),
+            ("res", "success".into()),
+        ])
+    )]
+    fn test_parse_msg_fields(#[case] input: &str, #[case] expected: RawFields<'_>) {
+        let (remaining, result) = parse_msg_fields(input).unwrap();
+        assert!(remaining.is_empty());
+        assert_eq!(result, expected);
+    }
+
+    #[rstest]
+    #[case::trailing_data("key1=value1 foo")]
+    #[case::invalid_key_value("foo")]
+    #[case::empty("")]
+    fn test_parse_msg_fields_fails(#[case] input: &str) {
+        assert!(parse_msg_fields(input).is_err());
     }
 
     #[test]
@@ -194,6 +515,52 @@ mod tests {
             "key=value{ENRICHMENT_SEPARATOR}enriched_key=enriched_value{ENRICHMENT_SEPARATOR}"
         );
 
-        assert!(dbg!(parse_body(&line)).is_err());
+        assert!(dbg!(parse_body(&line, &RecordType::Syscall)).is_err());
+    }
+
+    #[rstest]
+    #[case::valid("key=value", Fields::from([("key", "value".into())]), vec![])]
+    #[case::with_prefix("foo: key=value bar", Fields::from([("text", "foo".into()), ("key", "value".into())]), vec!["bar"])]
+    #[case::only_garbage("=foo =bar", Fields::new(), vec!["=foo", "=bar"])]
+    #[case::unterminated_quote("key=value 'foo bar", Fields::from([("key", "value".into())]), vec!["'foo bar"])]
+    fn test_parse_lenient_body(
+        #[case] input: &str,
+        #[case] expected_fields: RawFields<'_>,
+        #[case] expected_skipped: Vec<&str>,
+    ) {
+        let LenientBody { body, skipped } = parse_lenient_body(input, &RecordType::DaemonStart);
+        let skipped: Vec<_> = skipped.into_iter().map(|(segment, _)| segment).collect();
+        assert_eq!(body.fields, expected_fields);
+        assert_eq!(skipped, expected_skipped);
+    }
+
+    #[test]
+    fn test_parse_lenient_body_quoted_enrichment_separator() {
+        let input = format!(
+            "key=\"foo{ENRICHMENT_SEPARATOR}bar\" =baz{ENRICHMENT_SEPARATOR}enriched_key=enriched_value"
+        );
+        let LenientBody { body, skipped } = parse_lenient_body(&input, &RecordType::Syscall);
+        assert_eq!(
+            body.fields,
+            Fields::from([(
+                "key",
+                RawValue::Quoted(format!("foo{ENRICHMENT_SEPARATOR}bar").into())
+            )])
+        );
+        assert_eq!(
+            body.enrichment,
+            Some(Fields::from([("enriched_key", "enriched_value".into())]))
+        );
+        let skipped: Vec<_> = skipped.into_iter().map(|(segment, _)| segment).collect();
+        assert_eq!(skipped, vec!["=baz"]);
+    }
+
+    #[test]
+    fn test_parse_lenient_body_invalid_enrichment() {
+        let input = format!("key=value{ENRICHMENT_SEPARATOR}=foo");
+        let LenientBody { body, skipped } = parse_lenient_body(&input, &RecordType::Syscall);
+        assert_eq!(body.fields, Fields::from([("key", "value".into())]));
+        assert_eq!(body.enrichment, None);
+        assert_eq!(skipped, vec![("=foo", "=foo")]);
     }
 }
@@ -0,0 +1,78 @@
+use nom::bytes::complete::{tag, take_while};
+use nom::character::complete::{alpha1, char, space0, space1};
+use nom::sequence::{delimited, preceded, terminated};
+use nom::{IResult, Parser};
+
+pub const AVC_DECISION_KEY: &str = "avc_decision";
+pub const AVC_PERMISSIONS_KEY: &str = "permissions";
+
+/// Parses the free-form prefix of `SELinux` AVC records, which precedes the regular key-value pairs.
+/// The decision and the permissions are returned as regular key-value pairs, being the permissions
+/// separated by spaces, as they are logged by the kernel.
+///
+/// Example: `avc:  denied  { read write } for  `
+// Ref: https://github.com/torvalds/linux/blob/4856ebd997159f198e3177e515bda01143727463/security/selinux/avc.c
+pub fn parse_avc_prefix(input: &str) -> IResult<&str, Vec<(&'static str, &str)>> {
+    let (input, decision) =
+        delimited(terminated(tag("avc:"), space1), alpha1, space1).parse(input)?;
+    let (input, permissions) = terminated(
+        delimited(
+            char('{'),
+            preceded(space0, take_while(|c| c != '}')),
+            char('}'),
+        ),
+        delimited(space1, tag("for"), space1),
+    )
+    .parse(input)?;
+
+    Ok((
+        input,
+        vec![
+            (AVC_DECISION_KEY, decision),
+            (AVC_PERMISSIONS_KEY, permissions.trim_end()),
+        ],
+    ))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rstest::rstest;
+
+    #[rstest]
+    #[case::denied_single_permission("avc:  denied  { read } for  ", "denied", "read")]
+    #[case::denied_multiple_permissions(
+        "avc:  denied  { read write } for  ",
+        "denied",
+        "read write"
+    )]
+    #[case::granted("avc:  granted  { setsecparam } for  ", "granted", "setsecparam")]
+    #[case::single_spaces("avc: denied {read} for ", "denied", "read")]
+    fn test_parse_avc_prefix(
+        #[case] input: &str,
+        #[case] expected_decision: &str,
+        #[case] expected_permissions: &str,
+    ) {
+        let (remaining, result) = parse_avc_prefix(input).unwrap();
+        assert!(remaining.is_empty());
+        assert_eq!(
+            result,
+            vec![
+                (AVC_DECISION_KEY, expected_decision),
+                (AVC_PERMISSIONS_KEY, expected_permissions),
+            ]
+        );
+    }
+
+    #[rstest]
+    #[case::missing_avc_tag("denied  { read } for  ")]
+    #[case::missing_decision("avc:  { read } for  ")]
+    #[case::missing_permissions("avc:  denied  for  ")]
+    #[case::unterminated_permissions("avc:  denied  { read for  ")]
+    #[case::missing_for("avc:  denied  { read } pid=1")]
+    #[case::key_value("pid=1")]
+    #[case::empty("")]
+    fn test_parse_avc_prefix_fails(#[case] input: &str) {
+        assert!(parse_avc_prefix(input).is_err());
+    }
+}
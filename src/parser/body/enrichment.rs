@@ -0,0 +1,189 @@
+use std::borrow::Cow;
+
+use nom::branch::alt;
+use nom::bytes::complete::take_while1;
+use nom::character::complete::{char, space0, space1};
+use nom::combinator::{all_consuming, recognize, success};
+use nom::error::{Error, ErrorKind};
+use nom::multi::{many0, separated_list1};
+use nom::sequence::{delimited, preceded, separated_pair};
+use nom::{AsChar, Err, IResult, Parser};
+
+use super::ENRICHMENT_SEPARATOR;
+use super::RawFields;
+use super::key::parse_key;
+use super::parse_key_value_list;
+use super::text::parse_word;
+use super::value::parse_quoted_value;
+use crate::Fields;
+
+/// Parses a value surrounded by braces, which may contain nested braces.
+/// The braces are kept in the parsed value, so it can be told apart from
+/// regular values when interpreting it.
+///
+/// Example: `{ fam=local path=/tmp/{9e2cb087} }`
+fn parse_braced_value(input: &str) -> IResult<&str, &str> {
+    if !input.starts_with('{') {
+        return Err(Err::Error(Error::new(input, ErrorKind::Char)));
+    }
+
+    let mut depth = 0usize;
+    for (index, c) in input.char_indices() {
+        match c {
+            '{' => depth += 1,
+            '}' => depth -= 1,
+            _ => continue,
+        }
+
+        if depth == 0 {
+            let (value, remaining) = input.split_at(index + 1);
+            return Ok((remaining, value));
+        }
+    }
+
+    Err(Err::Error(Error::new(input, ErrorKind::Char)))
+}
+
+/// Parses an unquoted enrichment value, which may span several words as long as
+/// they are not followed by `=` (and so they are not the key of the next pair).
+///
+/// Example: `unknown family(0)`
+fn parse_multi_word_value(input: &str) -> IResult<&str, &str> {
+    recognize((
+        take_while1(|c: char| !c.is_space() && c != ENRICHMENT_SEPARATOR),
+        many0(preceded(char(' '), parse_word)),
+    ))
+    .parse(input)
+}
+
+/// Parses the value of an enriched field.
+fn parse_enrichment_value(input: &str) -> IResult<&str, Cow<'_, str>> {
+    alt((
+        parse_quoted_value,
+        alt((parse_braced_value, parse_multi_word_value, success(""))).map(Cow::Borrowed),
+    ))
+    .parse(input)
+}
+
+fn parse_enrichment_key_value(input: &str) -> IResult<&str, (Cow<'_, str>, Cow<'_, str>)> {
+    separated_pair(parse_key, char('='), parse_enrichment_value).parse(input)
+}
+
+/// Parses the enriched fields that follow the enrichment separator. Unlike regular fields,
+/// their values may be multi-word text or a group of key-value pairs between braces.
+///
+/// Example: `AUID="root" SADDR={ fam=local path=/tmp/foo }`
+pub fn parse_enrichment(input: &str) -> IResult<&str, Fields<Cow<'_, str>, Cow<'_, str>>> {
+    preceded(space0, separated_list1(space1, parse_enrichment_key_value))
+        .map(Fields::from_iter)
+        .parse(input)
+}
+
+/// Parses the key-value pairs of a braced enrichment value.
+///
+/// Example: `{ fam=inet laddr=127.0.0.1 lport=80 }`
+pub fn parse_braced_fields(input: &str) -> IResult<&str, RawFields<'_>> {
+    all_consuming(delimited(
+        char('{'),
+        parse_key_value_list,
+        (space0, char('}')),
+    ))
+    .parse(input)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rstest::rstest;
+
+    #[rstest]
+    #[case::regular("{ fam=local }", "{ fam=local }", "")]
+    #[case::nested("{ path=/tmp/{foo} } KEY=value", "{ path=/tmp/{foo} }", " KEY=value")]
+    #[case::empty("{}", "{}", "")]
+    fn test_parse_braced_value(
+        #[case] input: &str,
+        #[case] expected: &str,
+        #[case] expected_remaining: &str,
+    ) {
+        let (remaining, result) = parse_braced_value(input).unwrap();
+        assert_eq!(remaining, expected_remaining);
+        assert_eq!(result, expected);
+    }
+
+    #[rstest]
+    #[case::unterminated("{ fam=local")]
+    #[case::unterminated_nested("{ path=/tmp/{foo }")]
+    #[case::not_braced("fam=local")]
+    #[case::empty("")]
+    fn test_parse_braced_value_fails(#[case] input: &str) {
+        assert!(parse_braced_value(input).is_err());
+    }
+
+    #[rstest]
+    #[case::single_word("root", "root", "")]
+    #[case::multiple_words("unknown family(0)", "unknown family(0)", "")]
+    #[case::stops_at_next_key("unknown family KEY=value", "unknown family", " KEY=value")]
+    #[case::stops_at_separator(
+        &format!("unknown family{ENRICHMENT_SEPARATOR}"),
+        "unknown family",
+        &ENRICHMENT_SEPARATOR.to_string()
+    )]
+    fn test_parse_multi_word_value(
+        #[case] input: &str,
+        #[case] expected: &str,
+        #[case] expected_remaining: &str,
+    ) {
+        let (remaining, result) = parse_multi_word_value(input).unwrap();
+        assert_eq!(remaining, expected_remaining);
+        assert_eq!(result, expected);
+    }
+
+    #[rstest]
+    #[case::quoted("AUID=\"root\"", Fields::from([("AUID", "root".into())]))]
+    #[case::braced("SADDR={ fam=local path=/tmp/{foo} }",
+        Fields::from([("SADDR", "{ fam=local path=/tmp/{foo} }".into())])
+    )]
+    #[case::multi_word("SADDR=unknown family(0)",
+        Fields::from([("SADDR", "unknown family(0)".into())])
+    )]
+    #[case::multiple("AUID=\"root\" SADDR=unknown family UID=\"root\"",
+        Fields::from([
+            ("AUID", "root".into()),
+            ("SADDR", "unknown family".into()),
+            ("UID", "root".into()),
+        ])
+    )]
+    fn test_parse_enrichment(
+        #[case] input: &str,
+        #[case] expected: Fields<Cow<'_, str>, Cow<'_, str>>,
+    ) {
+        let (remaining, result) = parse_enrichment(input).unwrap();
+        assert!(remaining.is_empty());
+        assert_eq!(result, expected);
+    }
+
+    #[rstest]
+    #[case::regular("{ fam=local path=/tmp/{foo} }",
+        Fields::from([("fam", "local".into()), ("path", "/tmp/{foo}".into())])
+    )]
+    #[case::inet("{ fam=inet laddr=127.0.0.1 lport=80 }",
+        Fields::from([
+            ("fam", "inet".into()),
+            ("laddr", "127.0.0.1".into()),
+            ("lport", "80".into()),
+        ])
+    )]
+    fn test_parse_braced_fields(#[case] input: &str, #[case] expected: RawFields<'_>) {
+        let (_, result) = parse_braced_fields(input).unwrap();
+        assert_eq!(result, expected);
+    }
+
+    #[rstest]
+    #[case::not_braced("fam=local")]
+    #[case::unterminated("{ fam=local")]
+    #[case::trailing_data("{ fam=local } foo")]
+    #[case::empty("{}")]
+    fn test_parse_braced_fields_fails(#[case] input: &str) {
+        assert!(parse_braced_fields(input).is_err());
+    }
+}
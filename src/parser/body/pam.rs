@@ -0,0 +1,178 @@
+use std::borrow::Cow;
+
+use nom::branch::alt;
+use nom::bytes::complete::{tag, take_while1};
+use nom::character::complete::{char, space0, space1};
+use nom::combinator::recognize;
+use nom::multi::{many1, separated_list1};
+use nom::sequence::{delimited, preceded, separated_pair};
+use nom::{AsChar, IResult, Parser};
+
+use super::key::parse_key;
+use super::parse_key_value;
+use super::text::parse_word;
+use super::value::{RawValue, parse_quoted_value};
+
+pub const PAM_OPERATION_KEY: &str = "op";
+
+/// Parses the `PAM: session open` operation phrase of legacy PAM messages.
+fn parse_operation(input: &str) -> IResult<&str, &str> {
+    recognize((tag("PAM:"), many1(preceded(space1, parse_word)))).parse(input)
+}
+
+/// Parses a key-value pair inside a parenthesized group, where unquoted values
+/// are also terminated by commas and by the closing parenthesis.
+fn parse_group_key_value(input: &str) -> IResult<&str, (Cow<'_, str>, RawValue<'_>)> {
+    separated_pair(
+        parse_key,
+        char('='),
+        alt((
+            parse_quoted_value.map(RawValue::Quoted),
+            take_while1(|c: char| c != ',' && c != ')' && !c.is_space())
+                .map(|value: &str| RawValue::Unquoted(Cow::Borrowed(value))),
+        )),
+    )
+    .parse(input)
+}
+
+/// Parses a parenthesized group of key-value pairs, separated by commas and/or spaces.
+///
+/// Example: `(hostname=?, addr=?, terminal=cron res=success)`
+fn parse_group(input: &str) -> IResult<&str, Vec<(Cow<'_, str>, RawValue<'_>)>> {
+    delimited(
+        char('('),
+        separated_list1(
+            alt((recognize((char(','), space0)), space1)),
+            parse_group_key_value,
+        ),
+        char(')'),
+    )
+    .parse(input)
+}
+
+/// Parses an item of a legacy PAM message: a parenthesized group, a key-value pair
+/// or the lone `:` separator (which does not yield any key-value pair).
+fn parse_item(input: &str) -> IResult<&str, Vec<(Cow<'_, str>, RawValue<'_>)>> {
+    alt((
+        parse_group,
+        parse_key_value.map(|key_value| vec![key_value]),
+        char(':').map(|_| vec![]),
+    ))
+    .parse(input)
+}
+
+/// Parses legacy PAM userspace messages, as logged by RHEL5-era Linux PAM versions.
+/// The operation phrase is returned as the `op` key-value pair.
+///
+/// Example: `PAM: session open acct=root : exe="/usr/sbin/crond" (hostname=?, addr=?, terminal=cron res=success)`
+pub fn parse_legacy_pam_message(input: &str) -> IResult<&str, Vec<(Cow<'_, str>, RawValue<'_>)>> {
+    (
+        parse_operation,
+        preceded(space1, separated_list1(space1, parse_item)),
+    )
+        .map(|(operation, items)| {
+            let operation = (
+                Cow::Borrowed(PAM_OPERATION_KEY),
+                RawValue::Unquoted(Cow::Borrowed(operation)),
+            );
+            std::iter::once(operation)
+                .chain(items.into_iter().flatten())
+                .collect()
+        })
+        .parse(input)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rstest::rstest;
+
+    #[rstest]
+    #[case::single_word("PAM: accounting", "PAM: accounting")]
+    #[case::multiple_words("PAM: session open", "PAM: session open")]
+    #[case::stops_at_key_value("PAM: session open acct=root", "PAM: session open")]
+    fn test_parse_operation(#[case] input: &str, #[case] expected: &str) {
+        let (_, result) = parse_operation(input).unwrap();
+        assert_eq!(result, expected);
+    }
+
+    #[rstest]
+    #[case::without_prefix("session open")]
+    #[case::without_words("PAM:")]
+    #[case::key_value("PAM: acct=root")]
+    #[case::empty("")]
+    fn test_parse_operation_fails(#[case] input: &str) {
+        assert!(parse_operation(input).is_err());
+    }
+
+    #[rstest]
+    #[case::comma_separated("(hostname=?, addr=?)", vec![
+        ("hostname".into(), "?".into()),
+        ("addr".into(), "?".into()),
+    ])]
+    #[case::mixed_separators("(hostname=?, addr=?, terminal=cron res=success)", vec![
+        ("hostname".into(), "?".into()),
+        ("addr".into(), "?".into()),
+        ("terminal".into(), "cron".into()),
+        ("res".into(), "success".into()),
+    ])]
+    #[case::quoted_value("(exe=\"/usr/sbin/crond\")", vec![
+        ("exe".into(), RawValue::Quoted("/usr/sbin/crond".into())),
+    ])]
+    fn test_parse_group(#[case] input: &str, #[case] expected: Vec<(Cow<'_, str>, RawValue<'_>)>) {
+        let (remaining, result) = parse_group(input).unwrap();
+        assert!(remaining.is_empty());
+        assert_eq!(result, expected);
+    }
+
+    #[rstest]
+    #[case::unterminated("(hostname=?, addr=?")]
+    #[case::empty_group("()")]
+    #[case::not_key_value("(foo)")]
+    #[case::empty("")]
+    fn test_parse_group_fails(#[case] input: &str) {
+        assert!(parse_group(input).is_err());
+    }
+
+    #[rstest]
+    #[case::without_group(
+        "PAM: accounting acct=root : exe=\"/usr/sbin/crond\" hostname=? addr=? terminal=cron res=success",
+        vec![
+            ("op".into(), "PAM: accounting".into()),
+            ("acct".into(), "root".into()),
+            ("exe".into(), RawValue::Quoted("/usr/sbin/crond".into())),
+            ("hostname".into(), "?".into()),
+            ("addr".into(), "?".into()),
+            ("terminal".into(), "cron".into()),
+            ("res".into(), "success".into()),
+        ]
+    )]
+    #[case::with_group(
+        "PAM: session open acct=root : exe=\"/usr/sbin/crond\" (hostname=?, addr=?, terminal=cron res=success)",
+        vec![
+            ("op".into(), "PAM: session open".into()),
+            ("acct".into(), "root".into()),
+            ("exe".into(), RawValue::Quoted("/usr/sbin/crond".into())),
+            ("hostname".into(), "?".into()),
+            ("addr".into(), "?".into()),
+            ("terminal".into(), "cron".into()),
+            ("res".into(), "success".into()),
+        ]
+    )]
+    fn test_parse_legacy_pam_message(
+        #[case] input: &str,
+        #[case] expected: Vec<(Cow<'_, str>, RawValue<'_>)>,
+    ) {
+        let (remaining, result) = parse_legacy_pam_message(input).unwrap();
+        assert!(remaining.is_empty());
+        assert_eq!(result, expected);
+    }
+
+    #[rstest]
+    #[case::modern_format("op=PAM:accounting acct=\"root\"")]
+    #[case::without_fields("PAM: accounting")]
+    #[case::empty("")]
+    fn test_parse_legacy_pam_message_fails(#[case] input: &str) {
+        assert!(parse_legacy_pam_message(input).is_err());
+    }
+}
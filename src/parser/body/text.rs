@@ -0,0 +1,85 @@
+use nom::bytes::complete::take_while1;
+use nom::character::complete::{char, space1};
+use nom::combinator::{not, recognize};
+use nom::multi::many1;
+use nom::sequence::terminated;
+use nom::{AsChar, IResult, Parser};
+
+use super::ENRICHMENT_SEPARATOR;
+
+pub const TEXT_KEY: &str = "text";
+
+/// Parses a word of free-form text, which must not be the key of a key-value pair.
+pub fn parse_word(input: &str) -> IResult<&str, &str> {
+    terminated(
+        take_while1(|c: char| c != '=' && !c.is_space() && c != ENRICHMENT_SEPARATOR),
+        not(char('=')),
+    )
+    .parse(input)
+}
+
+/// Parses the free-form text that some records (such as `DAEMON_START` or `MAC_POLICY_LOAD`)
+/// have before their key-value pairs. The text is returned as the `text` key-value pair,
+/// without the trailing punctuation that separates it from the key-value pairs.
+///
+/// Example: `auditd start, ` or `netlabel: `
+pub fn parse_text_prefix(input: &str) -> IResult<&str, Vec<(&'static str, &str)>> {
+    recognize(many1(terminated(parse_word, space1)))
+        .map(|text: &str| {
+            let text = text.trim_end().trim_end_matches([',', ':']);
+            vec![(TEXT_KEY, text)]
+        })
+        .parse(input)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rstest::rstest;
+
+    #[rstest]
+    #[case::word("foo", "foo")]
+    #[case::punctuation("foo,", "foo,")]
+    #[case::stops_at_space("foo bar", "foo")]
+    fn test_parse_word(#[case] input: &str, #[case] expected: &str) {
+        let (_, result) = parse_word(input).unwrap();
+        assert_eq!(result, expected);
+    }
+
+    #[rstest]
+    #[case::key("foo=bar")]
+    #[case::space(" foo")]
+    #[case::empty("")]
+    fn test_parse_word_fails(#[case] input: &str) {
+        assert!(parse_word(input).is_err());
+    }
+
+    #[rstest]
+    #[case::single_word("policy ", "policy")]
+    #[case::multiple_words("policy loaded ", "policy loaded")]
+    #[case::trailing_comma("auditd start, ", "auditd start")]
+    #[case::trailing_colon("netlabel: ", "netlabel")]
+    #[case::inner_comma("auditd normal halt, sending ", "auditd normal halt, sending")]
+    #[case::multiple_spaces("policy  loaded  ", "policy  loaded")]
+    fn test_parse_text_prefix(#[case] input: &str, #[case] expected: &str) {
+        let (remaining, result) = parse_text_prefix(input).unwrap();
+        assert!(remaining.is_empty());
+        assert_eq!(result, vec![(TEXT_KEY, expected)]);
+    }
+
+    #[rstest]
+    #[case::stops_at_key_value("policy loaded auid=0", "auid=0")]
+    #[case::stops_at_last_word("policy loaded", "loaded")]
+    fn test_parse_text_prefix_remaining(#[case] input: &str, #[case] expected_remaining: &str) {
+        let (remaining, _) = parse_text_prefix(input).unwrap();
+        assert_eq!(remaining, expected_remaining);
+    }
+
+    #[rstest]
+    #[case::without_trailing_space("policy")]
+    #[case::key_value("auid=0 ")]
+    #[case::empty("")]
+    fn test_parse_text_prefix_fails(#[case] input: &str) {
+        assert!(parse_text_prefix(input).is_err());
+    }
+}
@@ -1,35 +1,176 @@
+use std::borrow::Cow;
+
 use nom::AsChar;
 use nom::branch::alt;
-use nom::bytes::complete::{take_while, take_while1};
-use nom::character::complete::char;
-use nom::sequence::delimited;
-use nom::{IResult, Parser};
+use nom::bytes::complete::take_while1;
+use nom::character::complete::{alpha1, char, one_of};
+use nom::combinator::{not, recognize};
+use nom::error::{Error, ErrorKind};
+use nom::sequence::{delimited, preceded};
+use nom::{Err, IResult, Parser};
 
 use super::ENRICHMENT_SEPARATOR;
 
+/// Value of a field as found in the record, keeping whether it was surrounded by quotes.
+/// auditd quotes values that are logged verbatim, while unquoted values of escaped fields
+/// are hex-encoded, so this is needed to interpret them properly.
+///
+/// Values are borrowed from the parsed input, unless they had to be unescaped.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum RawValue<'a> {
+    Quoted(Cow<'a, str>),
+    Unquoted(Cow<'a, str>),
+}
+
+impl RawValue<'_> {
+    #[must_use]
+    pub fn as_str(&self) -> &str {
+        match self {
+            Self::Quoted(value) | Self::Unquoted(value) => value,
+        }
+    }
+
+    #[must_use]
+    pub fn into_string(self) -> String {
+        match self {
+            Self::Quoted(value) | Self::Unquoted(value) => value.into_owned(),
+        }
+    }
+
+    /// Copies the value, so it no longer borrows the parsed input.
+    #[must_use]
+    pub fn into_owned(self) -> RawValue<'static> {
+        match self {
+            Self::Quoted(value) => RawValue::Quoted(Cow::Owned(value.into_owned())),
+            Self::Unquoted(value) => RawValue::Unquoted(Cow::Owned(value.into_owned())),
+        }
+    }
+}
+
+// Most of the tests deal with unquoted values, so this keeps them concise
+#[cfg(test)]
+impl<'a> From<&'a str> for RawValue<'a> {
+    fn from(value: &'a str) -> Self {
+        Self::Unquoted(Cow::Borrowed(value))
+    }
+}
+
 // TODO: reorder these functions so we go from high-level to low-level
 
+/// Keys whose unquoted values may be `AppArmor` labels, as logged by the kernel.
+const APPARMOR_LABEL_KEYS: [&str; 6] = [
+    "subj",
+    "obj",
+    "label",
+    "peer",
+    "subj_apparmor",
+    "obj_apparmor",
+];
+
+const DOUBLE_QUOTE: char = '"';
+const SINGLE_QUOTE: char = '\'';
+const ESCAPE: char = '\\';
+const QUOTES: &str = "\"'";
+
+/// Scans the content of a quoted string up to its closing `quote`, returning the unescaped
+/// content and the input remaining after the closing quote. If `escapes` is set, escaped
+/// closing quotes (such as `\"` inside double quotes) and escaped backslashes (`\\`) are
+/// unescaped, while other backslashes are kept as-is. Otherwise, the string ends at the
+/// first closing quote. The content is borrowed from the input, unless it had to be unescaped.
+///
+/// If `nested_quote` is given, quotes inside sections delimited by it do not terminate the string,
+/// and those sections are kept verbatim (escapes included), so they can be parsed later on.
+fn scan_quoted(
+    input: &str,
+    quote: char,
+    nested_quote: Option<char>,
+    escapes: bool,
+) -> Option<(Cow<'_, str>, &str)> {
+    // Only allocated once an escape is found, holding the content up to `copied`
+    let mut unescaped: Option<String> = None;
+    let mut copied = 0;
+    let mut nested = false;
+    let mut chars = input.char_indices().peekable();
+
+    while let Some((index, c)) = chars.next() {
+        match c {
+            ESCAPE if escapes => match chars.peek() {
+                Some(&(_, next)) if nested && (Some(next) == nested_quote || next == ESCAPE) => {
+                    chars.next();
+                }
+                Some(&(next_index, next)) if !nested && (next == quote || next == ESCAPE) => {
+                    chars.next();
+                    let value = unescaped.get_or_insert_with(String::new);
+                    value.push_str(&input[copied..index]);
+                    copied = next_index;
+                }
+                _ => {}
+            },
+            c if Some(c) == nested_quote => nested = !nested,
+            c if c == quote && !nested => {
+                let value = match unescaped {
+                    Some(mut value) => {
+                        value.push_str(&input[copied..index]);
+                        Cow::Owned(value)
+                    }
+                    None => Cow::Borrowed(&input[..index]),
+                };
+                return Some((value, &input[index + c.len_utf8()..]));
+            }
+            _ => {}
+        }
+    }
+
+    None
+}
+
+/// Whether a scanned quoted string is followed by the end of its field: the end of the input,
+/// a space, the enrichment separator or, for quoted keys, the equal sign.
+fn ends_field((_, remaining): &(Cow<'_, str>, &str)) -> bool {
+    remaining
+        .chars()
+        .next()
+        .is_none_or(|c| c.is_whitespace() || c == ENRICHMENT_SEPARATOR || c == '=')
+}
+
 /// Parses a string value, which can be surrounded by single or double quotes.
-// TODO: create a parse_string method and use it also in the key parser?
-fn parse_quoted_value(input: &str) -> IResult<&str, &str> {
-    const DOUBLE_QUOTE: char = '"';
-    const SINGLE_QUOTE: char = '\'';
+///
+/// Kernel records do not escape their double quoted values (values with quotes are hex-encoded
+/// instead), so backslashes are kept verbatim, as in `name="a\\b"` or `comm="foo\"`. Only if
+/// the first closing quote does not end the field, quotes escaped with a backslash (such as in
+/// `"foo\"bar"`) do not terminate the string.
+///
+/// Single quoted strings (the `msg='...'` field of userspace records) do handle escapes, and
+/// may contain double quoted values with single quotes inside, as in `msg='acct="system-property('xsl:vendor')/>"'`,
+/// so double quoted sections are skipped when looking for the closing single quote. If those
+/// are not balanced, the string is terminated at the first single quote instead, and if the
+/// escaped string does not end its field, the backslashes are kept verbatim.
+pub fn parse_quoted_value(input: &str) -> IResult<&str, Cow<'_, str>> {
+    let error = || Err::Error(Error::new(input, ErrorKind::Char));
 
-    // TODO: handle scaped double quote inside double quoted string with `escaped` parser
-    // TODO: handle scaped single quote inside single quoted string with `escaped` parser
-    alt((
-        delimited(
-            char(DOUBLE_QUOTE),
-            take_while(|c| c != DOUBLE_QUOTE),
-            char(DOUBLE_QUOTE),
+    let mut chars = input.chars();
+    // Nested quote and whether escapes are handled, in order of preference
+    let (quote, attempts): (char, &[(Option<char>, bool)]) = match chars.next() {
+        Some(DOUBLE_QUOTE) => (DOUBLE_QUOTE, &[(None, false), (None, true)]),
+        Some(SINGLE_QUOTE) => (
+            SINGLE_QUOTE,
+            &[(Some(DOUBLE_QUOTE), true), (None, true), (None, false)],
         ),
-        delimited(
-            char(SINGLE_QUOTE),
-            take_while(|c| c != SINGLE_QUOTE),
-            char(SINGLE_QUOTE),
-        ),
-    ))
-    .parse(input)
+        _ => return Err(error()),
+    };
+
+    let content = chars.as_str();
+    let mut scanned = attempts
+        .iter()
+        .filter_map(|&(nested_quote, escapes)| scan_quoted(content, quote, nested_quote, escapes));
+    let first = scanned.next().ok_or_else(error)?;
+    let (value, remaining) = if ends_field(&first) {
+        first
+    } else {
+        scanned.find(ends_field).unwrap_or(first)
+    };
+
+    Ok((remaining, value))
 }
 
 // TODO: the maybe we have to make a parser out of the `take_while1(..)` as it is repeated
@@ -38,15 +179,50 @@ fn parse_unquoted_value(input: &str) -> IResult<&str, &str> {
     // If the value is not surrounded by quotes, take all the characters until a space or the enrichment separator is found.
     // For example, in the `op` field of auditd records: `op=PAM:accounting`, the value should be a string, but
     // it is not surrounded by quotes.
+    // Values starting with a quote are quoted values that are not terminated
     // TODO: use take_while0?
-    take_while1(|c: char| !c.is_space() && c != ENRICHMENT_SEPARATOR).parse(input)
+    preceded(
+        not(one_of(QUOTES)),
+        take_while1(|c: char| !c.is_space() && c != ENRICHMENT_SEPARATOR),
+    )
+    .parse(input)
+}
+
+/// Parses an `AppArmor` label, which is an unquoted profile followed by its mode between parenthesis.
+/// This is the only case where an unquoted value may contain a space.
+///
+/// Example: `/usr/sbin/ntpd (enforce)`
+// Ref: https://github.com/torvalds/linux/blob/master/security/apparmor/label.c
+fn parse_apparmor_label(input: &str) -> IResult<&str, &str> {
+    recognize((
+        parse_unquoted_value,
+        preceded(char(' '), delimited(char('('), alpha1, char(')'))),
+    ))
+    .parse(input)
+}
+
+/// Parses an unquoted value, which may be an `AppArmor` label for the keys that hold them.
+fn parse_unquoted_label_or_value<'a>(input: &'a str, key: &str) -> IResult<&'a str, &'a str> {
+    if APPARMOR_LABEL_KEYS.contains(&key) {
+        alt((parse_apparmor_label, parse_unquoted_value)).parse(input)
+    } else {
+        parse_unquoted_value(input)
+    }
 }
 
 /// Parses the value part of a field, the right side of the `key=value` pair.
-pub fn parse_value(input: &str) -> IResult<&str, String> {
-    alt((parse_quoted_value, parse_unquoted_value))
-        .map(ToString::to_string)
-        .parse(input)
+/// Missing values (such as in `subj= res=success`) are parsed as empty strings,
+/// the same as empty quoted values (`""`), while unterminated quoted values fail.
+pub fn parse_value<'a>(input: &'a str, key: &str) -> IResult<&'a str, RawValue<'a>> {
+    alt((
+        parse_quoted_value.map(RawValue::Quoted),
+        alt((
+            |input| parse_unquoted_label_or_value(input, key),
+            not(one_of(QUOTES)).map(|()| ""),
+        ))
+        .map(|value| RawValue::Unquoted(Cow::Borrowed(value))),
+    ))
+    .parse(input)
 }
 
 #[cfg(test)]
@@ -69,15 +245,55 @@ mod tests {
         "key1=value1 key2=value2 key3=value3"
     )]
     #[case::double_quoted_map("\"key1=value1 key2=value2\"", "key1=value1 key2=value2")]
-    // FIXME: this tests should not fail. We should treat escaped quotes properly
-    // #[case::escaped_double_quote(r#""foo\"bar""#, r#"foo"bar"#)]
-    // #[case::escaped_single_quote("'foo\'bar'", "foo'bar")]
+    #[case::escaped_double_quote(r#""foo\"bar""#, r#"foo"bar"#)]
+    #[case::escaped_single_quote(r"'foo\'bar'", "foo'bar")]
+    #[case::escaped_other_quote(r#""foo\'bar""#, r"foo\'bar")]
+    #[case::backslash(r#""foo\bar""#, r"foo\bar")]
+    #[case::single_quoted_with_double_quoted_value_inside(
+        r#"'acct="system-property('xsl:vendor')/>" res=failed'"#,
+        r#"acct="system-property('xsl:vendor')/>" res=failed"#
+    )]
+    #[case::single_quoted_with_escaped_double_quote_inside(
+        r#"'acct="foo\"'bar" res=failed'"#,
+        r#"acct="foo\"'bar" res=failed"#
+    )]
+    #[case::single_quoted_with_unbalanced_double_quote_inside("'foo\"bar'", "foo\"bar")]
+    #[case::backslashes(r#""a\\b""#, r"a\\b")]
+    #[case::trailing_backslashes(r#""foo\\""#, r"foo\\")]
+    #[case::single_quoted_escaped_backslash(r"'foo\\'", r"foo\")]
+    #[case::escaped_backslash_and_quote(r#""foo\\\"bar""#, r#"foo\"bar"#)]
+    #[case::trailing_backslash(r#""foo\""#, r"foo\")]
+    #[case::single_quoted_trailing_backslash(r"'foo\'", r"foo\")]
     fn test_parse_quoted_value(#[case] input: &str, #[case] expected: &str) {
         let (remaining, result) = parse_quoted_value(input).unwrap();
         assert!(remaining.is_empty());
         assert_eq!(result, expected);
     }
 
+    #[rstest]
+    #[case::trailing_backslash(r#""foo\" key="bar""#, r"foo\", r#" key="bar""#)]
+    #[case::backslash_before_space(r#""foo\" bar" key="baz""#, r"foo\", r#" bar" key="baz""#)]
+    #[case::single_quoted_escaped_quote(r"'foo\' bar' key='baz'", "foo' bar", " key='baz'")]
+    #[case::key(r#""foo\"="bar""#, r"foo\", r#"="bar""#)]
+    fn test_parse_quoted_value_remaining(
+        #[case] input: &str,
+        #[case] expected: &str,
+        #[case] expected_remaining: &str,
+    ) {
+        let (remaining, result) = parse_quoted_value(input).unwrap();
+        assert_eq!(remaining, expected_remaining);
+        assert_eq!(result, expected);
+    }
+
+    #[rstest]
+    #[case::without_escapes("\"foo bar\"", true)]
+    #[case::nested_escaped_quote(r#"'acct="foo\"'bar"'"#, true)]
+    #[case::escaped_quote(r#""foo\"bar""#, false)]
+    fn test_parse_quoted_value_borrows_input(#[case] input: &str, #[case] expected_borrowed: bool) {
+        let (_, result) = parse_quoted_value(input).unwrap();
+        assert_eq!(matches!(result, Cow::Borrowed(_)), expected_borrowed);
+    }
+
     #[rstest]
     #[case::unquoted("foo")]
     #[case::unquoted_with_space("foo bar")]
@@ -121,27 +337,81 @@ mod tests {
     #[case::empty("")]
     #[case::only_space(" ")]
     #[case::only_enrichment_separator(&ENRICHMENT_SEPARATOR.to_string())]
+    #[case::leading_double_quote("\"foo")]
+    #[case::leading_single_quote("'foo")]
     fn test_parse_unquoted_value_fails(#[case] input: &str) {
         assert!(parse_unquoted_value(input).is_err());
     }
 
     #[rstest]
-    #[case::double_quoted_string("\"foo\"", "foo")]
-    #[case::single_quoted_string("'foo'", "foo")]
-    #[case::unquoted_string("foo", "foo")]
-    #[case::map("'key=value'", "key=value")]
-    #[case::number("123", "123")]
-    fn test_parse_value(#[case] input: &str, #[case] expected: &str) {
-        let (remaining, result) = parse_value(input).unwrap();
+    #[case::enforce("/usr/sbin/ntpd (enforce)", "/usr/sbin/ntpd (enforce)")]
+    #[case::leading_equal_sign("=/usr/sbin/ntpd (enforce)", "=/usr/sbin/ntpd (enforce)")]
+    #[case::complain(
+        "/usr/bin/man//&man_groff (complain)",
+        "/usr/bin/man//&man_groff (complain)"
+    )]
+    fn test_parse_apparmor_label(#[case] input: &str, #[case] expected: &str) {
+        let (remaining, result) = parse_apparmor_label(input).unwrap();
         assert!(remaining.is_empty());
         assert_eq!(result, expected);
     }
 
+    #[rstest]
+    #[case::without_mode("/usr/sbin/ntpd")]
+    #[case::without_space("/usr/sbin/ntpd(enforce)")]
+    #[case::two_spaces("/usr/sbin/ntpd  (enforce)")]
+    #[case::unterminated_mode("/usr/sbin/ntpd (enforce")]
+    #[case::key_value_inside_parenthesis("foo (bar=baz)")]
+    #[case::empty("")]
+    fn test_parse_apparmor_label_fails(#[case] input: &str) {
+        assert!(parse_apparmor_label(input).is_err());
+    }
+
+    #[rstest]
+    #[case::double_quoted_string("\"foo\"", RawValue::Quoted("foo".into()))]
+    #[case::single_quoted_string("'foo'", RawValue::Quoted("foo".into()))]
+    #[case::unquoted_string("foo", RawValue::Unquoted("foo".into()))]
+    #[case::map("'key=value'", RawValue::Quoted("key=value".into()))]
+    #[case::number("123", RawValue::Unquoted("123".into()))]
+    #[case::escaped_quote(r#""foo\"bar""#, RawValue::Quoted(r#"foo"bar"#.into()))]
+    #[case::quoted_empty("\"\"", RawValue::Quoted("".into()))]
+    fn test_parse_value(#[case] input: &str, #[case] expected: RawValue) {
+        let (remaining, result) = parse_value(input, "key").unwrap();
+        assert!(remaining.is_empty());
+        assert_eq!(result, expected);
+    }
+
+    #[rstest]
+    #[case::subj("subj", "/usr/sbin/cupsd (enforce)", "/usr/sbin/cupsd (enforce)", "")]
+    #[case::label("label", "/usr/sbin/cupsd (enforce)", "/usr/sbin/cupsd (enforce)", "")]
+    #[case::other_key("key", "foo (bar)", "foo", " (bar)")]
+    fn test_parse_value_apparmor_label(
+        #[case] key: &str,
+        #[case] input: &str,
+        #[case] expected: &str,
+        #[case] expected_remaining: &str,
+    ) {
+        let (remaining, result) = parse_value(input, key).unwrap();
+        assert_eq!(remaining, expected_remaining);
+        assert_eq!(result, RawValue::Unquoted(expected.into()));
+    }
+
+    #[rstest]
+    #[case::double_quoted_not_terminated("\"foo")]
+    #[case::double_quoted_with_space_not_terminated("\"foo bar")]
+    #[case::single_quoted_not_terminated("'foo")]
+    fn test_parse_value_fails(#[case] input: &str) {
+        assert!(parse_value(input, "key").is_err());
+    }
+
     #[rstest]
     #[case::empty("")]
     #[case::only_space(" ")]
+    #[case::space_before_next_key_value(" key=value")]
     #[case::only_enrichment_separator(&ENRICHMENT_SEPARATOR.to_string())]
-    fn test_parse_value_fails(#[case] input: &str) {
-        assert!(parse_value(input).is_err());
+    fn test_parse_value_missing(#[case] input: &str) {
+        let (remaining, result) = parse_value(input, "key").unwrap();
+        assert_eq!(remaining, input);
+        assert_eq!(result, RawValue::Unquoted("".into()));
     }
 }
@@ -0,0 +1,142 @@
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+use thiserror::Error;
+
+use super::invalid_utf8::unescape_bytes;
+
+/// Maximum number of characters of the input included in errors
+const EXCERPT_LENGTH: usize = 32;
+
+/// Error returned when a record can not be parsed. Every variant carries the byte offset
+/// of the line where parsing failed and a short excerpt of the input from that offset.
+#[derive(Debug, Clone, PartialEq, Eq, Error)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub enum ParserError {
+    /// The `node=... type=... msg=audit(...): ` header is malformed
+    #[error("invalid header at offset {offset}: {excerpt:?}")]
+    InvalidHeader { offset: usize, excerpt: String },
+    /// The `timestamp:id` part of the header is malformed
+    #[error("invalid timestamp at offset {offset}: {excerpt:?}")]
+    InvalidTimestamp { offset: usize, excerpt: String },
+    /// A field is not a `key=value` pair
+    #[error("invalid key at offset {offset}: {excerpt:?}")]
+    InvalidKey { offset: usize, excerpt: String },
+    /// A quoted key or value is not terminated
+    #[error("unterminated quote at offset {offset}: {excerpt:?}")]
+    UnterminatedQuote { offset: usize, excerpt: String },
+    /// Data that is not part of any field, such as the text following a quoted value
+    #[error("trailing data at offset {offset}: {excerpt:?}")]
+    TrailingData { offset: usize, excerpt: String },
+}
+
+impl ParserError {
+    /// Byte offset of the line where parsing failed.
+    #[must_use]
+    pub fn offset(&self) -> usize {
+        match self {
+            Self::InvalidHeader { offset, .. }
+            | Self::InvalidTimestamp { offset, .. }
+            | Self::InvalidKey { offset, .. }
+            | Self::UnterminatedQuote { offset, .. }
+            | Self::TrailingData { offset, .. } => *offset,
+        }
+    }
+
+    /// Excerpt of the line starting where parsing failed.
+    #[must_use]
+    pub fn excerpt(&self) -> &str {
+        match self {
+            Self::InvalidHeader { excerpt, .. }
+            | Self::InvalidTimestamp { excerpt, .. }
+            | Self::InvalidKey { excerpt, .. }
+            | Self::UnterminatedQuote { excerpt, .. }
+            | Self::TrailingData { excerpt, .. } => excerpt,
+        }
+    }
+
+    /// Builds the error of a header that failed to parse at `remaining`.
+    pub(super) fn from_header(input: &str, remaining: &str) -> Self {
+        let offset = input.len() - remaining.len();
+        let excerpt = excerpt(remaining);
+
+        // Failures between `audit(` and `)` come from the timestamp or the id
+        let parsed = &input[..offset];
+        let in_audit_msg = parsed
+            .rfind("audit(")
+            .is_some_and(|start| !parsed[start..].contains(')'));
+
+        if in_audit_msg {
+            Self::InvalidTimestamp { offset, excerpt }
+        } else {
+            Self::InvalidHeader { offset, excerpt }
+        }
+    }
+
+    /// Builds the error of a body whose `remaining` data could not be parsed as fields.
+    pub(super) fn from_body(input: &str, remaining: &str) -> Self {
+        let separator = remaining.len() - remaining.trim_start().len();
+        let remaining = &remaining[separator..];
+        let offset = input.len() - remaining.len();
+        let excerpt = excerpt(remaining);
+
+        // Fields are separated by spaces, so parsing stopping in the middle of a word means
+        // that there is data right after a value (e.g. `key="value"foo`)
+        if separator == 0 && offset > 0 && !input[..offset].ends_with(char::is_whitespace) {
+            return Self::TrailingData { offset, excerpt };
+        }
+
+        if is_unterminated_quote(remaining) {
+            return Self::UnterminatedQuote { offset, excerpt };
+        }
+
+        let field = remaining
+            .split_once(char::is_whitespace)
+            .map_or(remaining, |(field, _)| field);
+
+        match field.split_once('=') {
+            Some((key, _)) if !key.is_empty() => Self::TrailingData { offset, excerpt },
+            _ => Self::InvalidKey { offset, excerpt },
+        }
+    }
+
+    /// Maps the error of a line whose invalid UTF-8 bytes were escaped to the original line,
+    /// so the offset points to the original bytes.
+    pub(super) fn unescape(self, escaped_input: &str) -> Self {
+        let unescape = |input: &str| unescape_bytes(input).unwrap_or_else(|| input.into());
+        let offset = unescape(&escaped_input[..self.offset()]).len();
+        let excerpt = String::from_utf8_lossy(&unescape(self.excerpt())).into_owned();
+
+        match self {
+            Self::InvalidHeader { .. } => Self::InvalidHeader { offset, excerpt },
+            Self::InvalidTimestamp { .. } => Self::InvalidTimestamp { offset, excerpt },
+            Self::InvalidKey { .. } => Self::InvalidKey { offset, excerpt },
+            Self::UnterminatedQuote { .. } => Self::UnterminatedQuote { offset, excerpt },
+            Self::TrailingData { .. } => Self::TrailingData { offset, excerpt },
+        }
+    }
+}
+
+fn excerpt(input: &str) -> String {
+    input.chars().take(EXCERPT_LENGTH).collect()
+}
+
+/// Whether the input starts with a quoted key or value, such as `"foo` or `key="foo`,
+/// that is not terminated.
+fn is_unterminated_quote(input: &str) -> bool {
+    let value = match input.split_once('=') {
+        Some((key, value))
+            if !key.is_empty()
+                && !key.contains(char::is_whitespace)
+                && !key.starts_with(['"', '\'']) =>
+        {
+            value
+        }
+        _ => input,
+    };
+
+    let mut chars = value.chars();
+    match chars.next() {
+        Some(quote @ ('"' | '\'')) => !chars.as_str().contains(quote),
+        _ => false,
+    }
+}
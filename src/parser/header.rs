@@ -1,56 +1,47 @@
-use nom::bytes::complete::{tag, take, take_while1};
+use nom::branch::alt;
+use nom::bytes::complete::{tag, take_while1};
 use nom::character::complete::{char, u64 as parse_u64};
-use nom::combinator::opt;
+use nom::combinator::{eof, opt};
 use nom::sequence::{delimited, preceded, separated_pair, terminated};
 use nom::{AsChar, IResult, Parser};
 
+use crate::timestamp::parse_unix_seconds;
+use crate::{AuditTimestamp, RecordType};
+
 #[derive(Debug)]
 #[cfg_attr(test, derive(PartialEq))]
-pub struct InnerHeader {
-    pub node: Option<String>,
-    pub record_type: String,
-    pub audit_msg: InnerAuditMsg,
+pub struct InnerHeader<'a> {
+    pub node: Option<&'a str>,
+    pub record_type: RecordType,
+    /// `None` when the record is logged without timestamp and id, as `msg=?`
+    pub audit_msg: Option<InnerAuditMsg>,
 }
 
 #[derive(Debug)]
 #[cfg_attr(test, derive(PartialEq))]
 pub struct InnerAuditMsg {
-    pub timestamp: u64,
+    pub timestamp: AuditTimestamp,
     pub id: u64,
 }
 
 // TODO: reorder these functions so we go from high-level to low-level
 
-fn parse_record_type(input: &str) -> IResult<&str, String> {
+/// Parses the `type=value ` part of the message. Besides names, record types may be
+/// logged as plain numbers (`type=1327`) or as `type=UNKNOWN[1327]`.
+fn parse_record_type(input: &str) -> IResult<&str, RecordType> {
     preceded(tag("type="), take_while1(|c: char| !c.is_space()))
-        .map(ToString::to_string)
+        .map(RecordType::from)
         .parse(input)
 }
 
 /// Parses the optional `node=value ` part of the message.
-fn parse_node(input: &str) -> IResult<&str, String> {
-    preceded(tag("node="), take_while1(|c: char| !c.is_space()))
-        .map(ToString::to_string)
-        .parse(input)
-}
-
-/// Parses the timestamp decimal part as milliseconds. It is important
-/// to note that just 3 decimal digits are su
-fn parse_timestamp_milliseconds(input: &str) -> IResult<&str, u64> {
-    take(3usize).and_then(parse_u64).parse(input)
-}
-
-/// Parses a timestamp in `1234.567` format, where the whole part
-/// are seconds and the decimal part are milliseconds.
-fn parse_timestamp(input: &str) -> IResult<&str, u64> {
-    separated_pair(parse_u64, tag("."), parse_timestamp_milliseconds)
-        .map(|(seconds, milliseconds)| seconds * 1000 + milliseconds)
-        .parse(input)
+fn parse_node(input: &str) -> IResult<&str, &str> {
+    preceded(tag("node="), take_while1(|c: char| !c.is_space())).parse(input)
 }
 
 /// Parses a timestamp and a UID in `1234.567:89` format.
-fn parse_timestamp_and_uid(input: &str) -> IResult<&str, (u64, u64)> {
-    separated_pair(parse_timestamp, tag(":"), parse_u64).parse(input)
+fn parse_timestamp_and_uid(input: &str) -> IResult<&str, (AuditTimestamp, u64)> {
+    separated_pair(parse_unix_seconds, tag(":"), parse_u64).parse(input)
 }
 
 /// Parses the `audit(1234.567:89)` part of the message.
@@ -61,9 +52,26 @@ fn parse_audit_msg_value(input: &str) -> IResult<&str, InnerAuditMsg> {
 }
 
 /// Parses the `msg=audit(1234.567:89): ` part of the message.
-fn parse_audit_msg(input: &str) -> IResult<&str, InnerAuditMsg> {
-    // TODO: allow for ":" and ": "? (with and without trailing space)
-    delimited(tag("msg="), parse_audit_msg_value, tag(": ")).parse(input)
+/// Some userspace records (such as `DAEMON_CONFIG`) are logged without the colon,
+/// so `msg=audit(1234.567:89) ` is also accepted. Records without body (such as `EOE`)
+/// may end right after the colon, without trailing space: `msg=audit(1234.567:89):`
+///
+/// Records whose timestamp and id are unknown are logged as `msg=?`, such as
+/// `type=UNKNOWN[1329] msg=?`, which is parsed as `None`.
+fn parse_audit_msg(input: &str) -> IResult<&str, Option<InnerAuditMsg>> {
+    preceded(
+        tag("msg="),
+        // Unknown values go first, so errors point to the timestamp of the known ones
+        alt((
+            terminated(char('?'), alt((eof, tag(" ")))).map(|_| None),
+            terminated(
+                parse_audit_msg_value,
+                alt((tag(": "), terminated(tag(":"), eof), tag(" "))),
+            )
+            .map(Some),
+        )),
+    )
+    .parse(input)
 }
 
 // TODO: parse `node` field of auditd records
@@ -71,7 +79,7 @@ fn parse_audit_msg(input: &str) -> IResult<&str, InnerAuditMsg> {
 ///
 /// Example: `type=USER_ACCT msg=audit(1725039526.208:52): `
 /// Example with node: `node=node.org type=USER_ACCT msg=audit(1725039526.208:52): `
-pub fn parse_header(input: &str) -> IResult<&str, InnerHeader> {
+pub fn parse_header(input: &str) -> IResult<&str, InnerHeader<'_>> {
     let (input, node) = opt(terminated(parse_node, char(' '))).parse(input)?;
     let (input, (record_type, audit_msg)) =
         separated_pair(parse_record_type, char(' '), parse_audit_msg).parse(input)?;
@@ -113,11 +121,14 @@ mod tests {
     }
 
     #[rstest]
-    #[case::regular("type=USER_ACCT", "USER_ACCT")]
-    #[case::quoted("type=\"USER_ACCT\"", "\"USER_ACCT\"")]
-    #[case::numeric("type=123", "123")]
-    #[case::special_chars("type=?USER_ACCT!", "?USER_ACCT!")]
-    fn test_parse_record_type(#[case] input: &str, #[case] expected: &str) {
+    #[case::regular("type=USER_ACCT", RecordType::UserAcct)]
+    #[case::quoted("type=\"USER_ACCT\"", RecordType::Other("\"USER_ACCT\"".into()))]
+    #[case::numeric("type=1327", RecordType::Proctitle)]
+    #[case::numeric_not_in_table("type=123", RecordType::Unknown(123))]
+    #[case::unknown("type=UNKNOWN[1329]", RecordType::Replace)]
+    #[case::unknown_not_in_table("type=UNKNOWN[123]", RecordType::Unknown(123))]
+    #[case::special_chars("type=?USER_ACCT!", RecordType::Other("?USER_ACCT!".into()))]
+    fn test_parse_record_type(#[case] input: &str, #[case] expected: RecordType) {
         let (remaining, result) = parse_record_type(input).unwrap();
         assert!(remaining.is_empty());
         assert_eq!(result, expected);
@@ -134,55 +145,17 @@ mod tests {
         assert!(parse_record_type(input).is_err());
     }
 
-    #[rstest]
-    #[case::regular("123", 123)]
-    #[case::leading_zeroes("001", 1)]
-    #[case::max_value("999", 999)]
-    #[case::min_value("000", 0)]
-    fn test_parse_timestamp_milliseconds(#[case] input: &str, #[case] expected: u64) {
-        let (remaining, result) = parse_timestamp_milliseconds(input).unwrap();
-        assert!(remaining.is_empty());
-        assert_eq!(result, expected);
-    }
-
-    #[rstest]
-    #[case::non_numeric("abc")]
-    #[case::less_than_3_digits("12")]
-    #[case::empty("")]
-    fn test_parse_timestamp_milliseconds_fail(#[case] input: &str) {
-        assert!(parse_timestamp_milliseconds(input).is_err());
-    }
-
-    #[rstest]
-    #[case::regular("123.456", 123_456)]
-    #[case::leading_zeroes("001.234", 1234)]
-    #[case::zero_seconds("000.123", 123)]
-    #[case::zero_milliseconds("123.000", 123_000)]
-    #[case::min_value("000.000", 0)]
-    fn test_parse_timestamp(#[case] input: &str, #[case] expected: u64) {
-        let (remaining, result) = parse_timestamp(input).unwrap();
-        assert!(remaining.is_empty());
-        assert_eq!(result, expected);
-    }
-
-    #[rstest]
-    #[case::with_invalid_seconds("abc.123")]
-    #[case::with_invalid_milliseconds("123.abc")]
-    #[case::without_milliseconds("123")]
-    #[case::two_consecutive_dots("123..456")]
-    #[case::non_numeric("abc")]
-    #[case::empty("")]
-    fn test_parse_timestamp_fails(#[case] input: &str) {
-        assert!(parse_timestamp(input).is_err());
-    }
-
     #[rstest]
     #[case::regular("123.456:789", (123_456, 789))]
     #[case::zero_milliseconds("123.000:789", (123_000, 789))]
     fn test_parse_timestamp_and_uid(#[case] input: &str, #[case] expected: (u64, u64)) {
+        let (expected_timestamp, expected_id) = expected;
         let (remaining, result) = parse_timestamp_and_uid(input).unwrap();
         assert!(remaining.is_empty());
-        assert_eq!(result, expected);
+        assert_eq!(
+            result,
+            (AuditTimestamp::from_millis(expected_timestamp), expected_id)
+        );
     }
 
     #[rstest]
@@ -195,7 +168,7 @@ mod tests {
     }
 
     #[rstest]
-    #[case::regular("audit(123.456:789)", InnerAuditMsg { timestamp: 123_456, id: 789 })]
+    #[case::regular("audit(123.456:789)", InnerAuditMsg { timestamp: AuditTimestamp::from_millis(123_456), id: 789 })]
     fn test_parse_audit_msg_value(#[case] input: &str, #[case] expected: InnerAuditMsg) {
         let (remaining, result) = parse_audit_msg_value(input).unwrap();
         assert!(remaining.is_empty());
@@ -214,8 +187,12 @@ mod tests {
     }
 
     #[rstest]
-    #[case::regular("msg=audit(123.456:789): ", InnerAuditMsg { timestamp: 123_456, id: 789 })]
-    fn test_parse_audit_msg(#[case] input: &str, #[case] expected: InnerAuditMsg) {
+    #[case::regular("msg=audit(123.456:789): ", Some(InnerAuditMsg { timestamp: AuditTimestamp::from_millis(123_456), id: 789 }))]
+    #[case::without_colon("msg=audit(123.456:789) ", Some(InnerAuditMsg { timestamp: AuditTimestamp::from_millis(123_456), id: 789 }))]
+    #[case::without_trailing_space_at_end("msg=audit(123.456:789):", Some(InnerAuditMsg { timestamp: AuditTimestamp::from_millis(123_456), id: 789 }))]
+    #[case::unknown("msg=?", None)]
+    #[case::unknown_with_body("msg=? ", None)]
+    fn test_parse_audit_msg(#[case] input: &str, #[case] expected: Option<InnerAuditMsg>) {
         let (remaining, result) = parse_audit_msg(input).unwrap();
         assert!(remaining.is_empty());
         assert_eq!(result, expected);
@@ -224,9 +201,10 @@ mod tests {
     #[rstest]
     #[case::with_invalid_audit_msg_value("msg=123.456:789): ")]
     #[case::without_prefix_key("audit(123.456:789): ")]
-    #[case::without_suffix_trailing_space("msg=audit(123.456:789):")]
+    #[case::without_suffix_trailing_space_before_body("msg=audit(123.456:789):key=value")]
     #[case::without_suffix_semicolon("msg=audit(123.456:789)")]
     #[case::without_audit_msg_value("msg=")]
+    #[case::unknown_with_trailing_data("msg=?foo")]
     #[case::without_prefix_and_suffix("audit(123.456:789)")]
     #[case::empty("")]
     fn test_parse_audit_msg_fails(#[case] input: &str) {
@@ -234,8 +212,9 @@ mod tests {
     }
 
     #[rstest]
-    #[case::regular("type=USER_ACCT msg=audit(123.456:789): ", InnerHeader { node: None, record_type: "USER_ACCT".to_string(), audit_msg: InnerAuditMsg { timestamp: 123_456, id: 789 } })]
-    #[case::with_node("node=node.org type=USER_ACCT msg=audit(123.456:789): ", InnerHeader { node: Some("node.org".to_string()), record_type: "USER_ACCT".to_string(), audit_msg: InnerAuditMsg { timestamp: 123_456, id: 789 } })]
+    #[case::regular("type=USER_ACCT msg=audit(123.456:789): ", InnerHeader { node: None, record_type: RecordType::UserAcct, audit_msg: Some(InnerAuditMsg { timestamp: AuditTimestamp::from_millis(123_456), id: 789 }) })]
+    #[case::with_node("node=node.org type=USER_ACCT msg=audit(123.456:789): ", InnerHeader { node: Some("node.org"), record_type: RecordType::UserAcct, audit_msg: Some(InnerAuditMsg { timestamp: AuditTimestamp::from_millis(123_456), id: 789 }) })]
+    #[case::unknown_audit_msg("type=UNKNOWN[1329] msg=?", InnerHeader { node: None, record_type: RecordType::from("UNKNOWN[1329]"), audit_msg: None })]
     fn test_parse_header(#[case] input: &str, #[case] expected: InnerHeader) {
         let (remaining, result) = parse_header(input).unwrap();
         assert!(remaining.is_empty());
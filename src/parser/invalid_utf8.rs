@@ -0,0 +1,102 @@
+use std::borrow::Cow;
+
+/// Invalid UTF-8 bytes (which are always `0x80` or greater) are escaped as the code point
+/// of this base plus the byte, which are private use code points from U+10FF80 to U+10FFFF.
+const ESCAPED_BYTE_BASE: u32 = 0x0010_FF00;
+const FIRST_ESCAPED_BYTE: char = '\u{10FF80}';
+
+/// Every escaped byte is encoded in UTF-8 starting with this byte
+const ESCAPED_BYTE_LEAD: u8 = 0xF4;
+
+fn is_escaped_byte(c: char) -> bool {
+    c >= FIRST_ESCAPED_BYTE
+}
+
+fn escape_byte(byte: u8) -> char {
+    char::from_u32(ESCAPED_BYTE_BASE + u32::from(byte)).unwrap_or(char::REPLACEMENT_CHARACTER)
+}
+
+fn push_escaped_bytes(output: &mut String, bytes: &[u8]) {
+    output.extend(bytes.iter().map(|&byte| escape_byte(byte)));
+}
+
+/// Converts a line to a string that can be parsed, escaping the bytes that are not valid UTF-8
+/// so they can be restored with [`unescape_bytes`]. Valid lines are borrowed as-is.
+///
+/// Characters of the line that are escaped bytes themselves are escaped byte by byte,
+/// so that restoring them yields the original bytes as well.
+pub fn escape_invalid_utf8(input: &[u8]) -> Cow<'_, str> {
+    if let Ok(input) = std::str::from_utf8(input) {
+        return Cow::Borrowed(input);
+    }
+
+    let mut escaped = String::with_capacity(input.len());
+    for chunk in input.utf8_chunks() {
+        for c in chunk.valid().chars() {
+            if is_escaped_byte(c) {
+                push_escaped_bytes(&mut escaped, c.encode_utf8(&mut [0; 4]).as_bytes());
+            } else {
+                escaped.push(c);
+            }
+        }
+        push_escaped_bytes(&mut escaped, chunk.invalid());
+    }
+
+    Cow::Owned(escaped)
+}
+
+/// Restores the bytes escaped by [`escape_invalid_utf8`], returning `None`
+/// if the value does not contain any escaped byte.
+pub fn unescape_bytes(input: &str) -> Option<Vec<u8>> {
+    // Cheap check to skip the values of valid lines, which are the vast majority
+    if !input.as_bytes().contains(&ESCAPED_BYTE_LEAD) || !input.contains(is_escaped_byte) {
+        return None;
+    }
+
+    let mut bytes = Vec::with_capacity(input.len());
+    for c in input.chars() {
+        if is_escaped_byte(c) {
+            // The escaped code points only span the last byte values
+            let byte = u8::try_from(u32::from(c) - ESCAPED_BYTE_BASE).unwrap_or(u8::MAX);
+            bytes.push(byte);
+        } else {
+            bytes.extend_from_slice(c.encode_utf8(&mut [0; 4]).as_bytes());
+        }
+    }
+
+    Some(bytes)
+}
+
+#[cfg(test)]
+mod tests {
+    use rstest::rstest;
+
+    use super::*;
+
+    #[rstest]
+    #[case::valid(b"comm=\"cat\"")]
+    #[case::invalid_byte(b"comm=\"c\xffat\"")]
+    #[case::truncated_sequence(b"comm=\"caf\xc3\"")]
+    #[case::valid_multibyte_around_invalid(b"comm=\"caf\xc3\xa9\xff\"")]
+    #[case::escaped_code_point_in_input(b"comm=\"\xf4\x8f\xbf\xbf\xff\"")]
+    fn test_escape_roundtrip(#[case] input: &[u8]) {
+        let escaped = escape_invalid_utf8(input);
+        let restored = unescape_bytes(&escaped).unwrap_or_else(|| escaped.as_bytes().to_vec());
+        assert_eq!(restored, input);
+    }
+
+    #[test]
+    fn test_escape_borrows_valid_input() {
+        assert!(matches!(
+            escape_invalid_utf8("comm=\"café\"".as_bytes()),
+            Cow::Borrowed(_)
+        ));
+    }
+
+    #[rstest]
+    #[case::ascii("cat")]
+    #[case::multibyte("café")]
+    fn test_unescape_without_escaped_bytes(#[case] input: &str) {
+        assert_eq!(unescape_bytes(input), None);
+    }
+}
@@ -0,0 +1,180 @@
+use std::io::{self, BufRead};
+
+use crate::{AuditdRecord, ParseOptions, ParserError};
+
+/// Record read by [`AuditLogReader`], along with its location in the source.
+#[derive(Debug)]
+pub struct ReadRecord {
+    /// Line number of the record, starting at 1
+    pub line_number: usize,
+    /// Byte offset of the start of the line in the source
+    pub offset: u64,
+    pub record: Result<AuditdRecord, ParserError>,
+}
+
+/// Reads audit records line by line from any [`BufRead`], such as a file wrapped
+/// in a [`BufReader`](std::io::BufReader) or the standard input.
+///
+/// Blank lines are skipped, as well as lines starting with `#` if enabled with
+/// [`AuditLogReader::skip_comments`]. Both `\n` and `\r\n` line endings are supported.
+///
+/// Lines are read as bytes, so lines that are not valid UTF-8 are parsed as well,
+/// keeping their invalid bytes (see [`AuditdRecord::parse_bytes`]).
+///
+/// The iterator yields an error when the source can not be read, while records that
+/// can not be parsed are yielded as a [`ReadRecord`] with a [`ParserError`].
+pub struct AuditLogReader<R> {
+    reader: R,
+    buffer: Vec<u8>,
+    line_number: usize,
+    offset: u64,
+    skip_comments: bool,
+    options: ParseOptions,
+}
+
+impl<R: BufRead> AuditLogReader<R> {
+    pub fn new(reader: R) -> Self {
+        Self {
+            reader,
+            buffer: Vec::new(),
+            line_number: 0,
+            offset: 0,
+            skip_comments: false,
+            options: ParseOptions::default(),
+        }
+    }
+
+    /// Skips lines starting with `#`.
+    #[must_use]
+    pub fn skip_comments(mut self, skip_comments: bool) -> Self {
+        self.skip_comments = skip_comments;
+        self
+    }
+
+    /// Parses the records with the given options.
+    #[must_use]
+    pub fn options(mut self, options: ParseOptions) -> Self {
+        self.options = options;
+        self
+    }
+
+    pub fn into_inner(self) -> R {
+        self.reader
+    }
+}
+
+impl<R: BufRead> Iterator for AuditLogReader<R> {
+    type Item = io::Result<ReadRecord>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        loop {
+            // The buffer is reused between lines to avoid allocating for every record
+            self.buffer.clear();
+            let bytes_read = match self.reader.read_until(b'\n', &mut self.buffer) {
+                Ok(0) => return None,
+                Ok(bytes_read) => bytes_read,
+                Err(err) => return Some(Err(err)),
+            };
+
+            let offset = self.offset;
+            self.offset += bytes_read as u64;
+            self.line_number += 1;
+
+            let mut line = self.buffer.as_slice();
+            while let [rest @ .., b'\n' | b'\r'] = line {
+                line = rest;
+            }
+            if line.trim_ascii().is_empty() || (self.skip_comments && line.starts_with(b"#")) {
+                continue;
+            }
+
+            return Some(Ok(ReadRecord {
+                line_number: self.line_number,
+                offset,
+                record: AuditdRecord::parse_bytes_with(line, &self.options),
+            }));
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::io::Cursor;
+
+    use rstest::rstest;
+
+    use super::*;
+    use crate::FieldValue;
+
+    const RECORD: &str = "type=foo msg=audit(1234.567:89): key=value";
+
+    fn read_locations(input: &str, skip_comments: bool) -> Vec<(usize, u64, bool)> {
+        AuditLogReader::new(Cursor::new(input))
+            .skip_comments(skip_comments)
+            .map(|read_record| {
+                let read_record = read_record.unwrap();
+                (
+                    read_record.line_number,
+                    read_record.offset,
+                    read_record.record.is_ok(),
+                )
+            })
+            .collect()
+    }
+
+    #[rstest]
+    #[case::single_line(RECORD, vec![(1, 0, true)])]
+    #[case::multiple_lines(&format!("{RECORD}\n{RECORD}\n"), vec![(1, 0, true), (2, 43, true)])]
+    #[case::crlf(&format!("{RECORD}\r\n{RECORD}\r\n"), vec![(1, 0, true), (2, 44, true)])]
+    #[case::blank_lines(&format!("\n{RECORD}\n  \n{RECORD}"), vec![(2, 1, true), (4, 47, true)])]
+    #[case::invalid_line(&format!("foo\n{RECORD}"), vec![(1, 0, false), (2, 4, true)])]
+    #[case::comments_not_skipped(&format!("# foo\n{RECORD}"), vec![(1, 0, false), (2, 6, true)])]
+    #[case::empty("", vec![])]
+    fn test_read_records(#[case] input: &str, #[case] expected: Vec<(usize, u64, bool)>) {
+        assert_eq!(read_locations(input, false), expected);
+    }
+
+    #[test]
+    fn test_read_records_skipping_comments() {
+        let input = format!("# foo\n{RECORD}\n#bar");
+        assert_eq!(read_locations(&input, true), vec![(2, 6, true)]);
+    }
+
+    #[test]
+    fn test_read_record_content() {
+        let read_record = AuditLogReader::new(Cursor::new(format!("{RECORD}\r\n")))
+            .next()
+            .unwrap()
+            .unwrap();
+        assert_eq!(read_record.record.unwrap(), RECORD.parse().unwrap());
+    }
+
+    #[test]
+    fn test_read_records_leniently() {
+        let input = format!("{RECORD} foo");
+        let read_record = AuditLogReader::new(Cursor::new(input))
+            .options(ParseOptions {
+                lenient: true,
+                ..Default::default()
+            })
+            .next()
+            .unwrap()
+            .unwrap();
+        assert_eq!(read_record.record.unwrap().unparsed, vec!["foo"]);
+    }
+
+    #[test]
+    fn test_read_records_with_invalid_utf8() {
+        let input = b"type=foo msg=audit(1234.567:89): comm=\"c\xffat\" key=value\n".to_vec();
+        let read_record = AuditLogReader::new(Cursor::new(input))
+            .next()
+            .unwrap()
+            .unwrap();
+        let record = read_record.record.unwrap();
+        assert_eq!(
+            record.fields.get("comm"),
+            Some(&FieldValue::Bytes(b"c\xffat".to_vec()))
+        );
+        assert_eq!(record.fields.get("key"), Some(&"value".into()));
+    }
+}
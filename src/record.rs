@@ -1,20 +1,36 @@
-use std::{collections::BTreeMap, str::FromStr};
+use std::{borrow::Cow, collections::BTreeMap, str::FromStr};
 
 #[cfg(feature = "serde")]
 use serde::{Deserialize, Serialize};
 
-use crate::parser::{self, ParserError};
+#[cfg(feature = "serde")]
+use crate::TimestampFormat;
+use crate::parser::{self, ParseOptions, ParserError};
+use crate::{
+    AuditArch, AuditFlag, AuditResult, AuditTimestamp, Errno, Fields, MacLabel, Mode, RecordType,
+    Signal, SocketAddr,
+};
+
+#[cfg(feature = "serde")]
+mod with_raw_values;
+#[cfg(feature = "serde")]
+mod with_timestamp_format;
+
+#[cfg(feature = "serde")]
+pub use with_raw_values::WithRawValues;
+#[cfg(feature = "serde")]
+pub use with_timestamp_format::WithTimestampFormat;
 
 #[derive(Debug, PartialEq, Eq)]
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct AuditdRecord {
     // TODO: rename `record_type` to `type`?
-    pub record_type: String,
-    /// Unix timestamp in milliseconds
-    pub timestamp: u64,
+    pub record_type: RecordType,
+    /// Timestamp shared by all the records of the event, `None` for records logged as `msg=?`
+    pub timestamp: Option<AuditTimestamp>,
 
-    /// Record identifier
-    pub id: u64,
+    /// Record identifier, `None` for records logged as `msg=?`
+    pub id: Option<u64>,
 
     /// Optional node field
     #[cfg_attr(
@@ -23,15 +39,92 @@ pub struct AuditdRecord {
     )]
     pub node: Option<String>,
 
-    // TODO: use index-ordered map?
-    pub fields: BTreeMap<String, FieldValue>,
+    pub fields: Fields<FieldValue>,
+
+    /// Values of the fields as they were logged, before being interpreted (e.g. `0` for
+    /// a `uid` interpreted as `root`). Fields built from others, such as `argv`, have no raw value.
+    ///
+    /// Empty unless the record is interpreted with
+    /// [`InterpretOptions::keep_raw_values`](crate::InterpretOptions::keep_raw_values).
+    ///
+    /// They are not serialized, see [`AuditdRecord::with_raw_values`] to serialize them along
+    /// with the interpreted values.
+    #[cfg_attr(feature = "serde", serde(skip))]
+    pub raw_fields: Fields<String>,
 
     #[cfg_attr(
         feature = "serde",
         serde(default, skip_serializing_if = "Option::is_none")
     )]
-    // TODO: use index-ordered map?
-    pub enrichment: Option<BTreeMap<String, FieldValue>>,
+    pub enrichment: Option<Fields<FieldValue>>,
+
+    /// Segments of the body that were skipped when parsing leniently
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Vec::is_empty")
+    )]
+    pub unparsed: Vec<String>,
+
+    /// Errors of the skipped segments, in the same order as `unparsed`
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Vec::is_empty")
+    )]
+    pub warnings: Vec<ParserError>,
+}
+
+impl AuditdRecord {
+    /// Parses a record with the given options, e.g. to skip malformed fields
+    /// instead of failing with [`ParseOptions::lenient`].
+    ///
+    /// # Errors
+    ///
+    /// Returns a [`ParserError`] if the line is not a valid record. Lenient parsing
+    /// only fails when the header is not valid.
+    pub fn parse_with(input: &str, options: &ParseOptions) -> Result<Self, ParserError> {
+        let raw_record = parser::parse_record_with(input, options)?;
+        Ok(Self::from_raw_with(raw_record, &options.interpret))
+    }
+
+    /// Parses a record from a line that may not be valid UTF-8. Values with invalid UTF-8
+    /// bytes are interpreted as [`FieldValue::Bytes`], so the original bytes are not lost.
+    ///
+    /// # Errors
+    ///
+    /// Returns a [`ParserError`] if the line is not a valid record.
+    pub fn parse_bytes(input: &[u8]) -> Result<Self, ParserError> {
+        Self::parse_bytes_with(input, &ParseOptions::default())
+    }
+
+    /// Parses a record from a line that may not be valid UTF-8, with the given options.
+    ///
+    /// # Errors
+    ///
+    /// Returns a [`ParserError`] if the line is not a valid record. Lenient parsing
+    /// only fails when the header is not valid.
+    pub fn parse_bytes_with(input: &[u8], options: &ParseOptions) -> Result<Self, ParserError> {
+        let raw_record = parser::parse_record_bytes(input, options)?;
+        Ok(Self::from_raw_with(raw_record, &options.interpret))
+    }
+
+    /// Serializes the record with every field as `{"raw": ..., "value": ...}`, so both
+    /// the value as it was logged and its interpretation are kept.
+    ///
+    /// Raw values are only kept when the record is interpreted with
+    /// [`InterpretOptions::keep_raw_values`](crate::InterpretOptions::keep_raw_values),
+    /// the fields are serialized with their value alone otherwise.
+    #[cfg(feature = "serde")]
+    #[must_use]
+    pub fn with_raw_values(&self) -> WithRawValues<'_> {
+        WithRawValues::new(self)
+    }
+
+    /// Serializes the record with its timestamp in the given format, such as RFC 3339.
+    #[cfg(feature = "serde")]
+    #[must_use]
+    pub fn with_timestamp_format(&self, format: TimestampFormat) -> WithTimestampFormat<'_, Self> {
+        WithTimestampFormat::new(self, format)
+    }
 }
 
 // TODO: add an array variant for things like `grantors=pam_unix,pam_permit,pam_time`
@@ -48,8 +141,38 @@ pub enum FieldValue {
     // TODO: Vec<String> or Vec<FieldValue>? is there any case
     // where we need an array of anything other than strings?
     Array(Vec<String>),
-    // TODO: use index-ordered map?
-    Map(BTreeMap<String, FieldValue>),
+    /// Decoded value that is not valid UTF-8
+    Bytes(Vec<u8>),
+    Map(Fields<FieldValue>),
+    // Interpreted values are serialized the same as the string or map they are shown as,
+    // so they cannot be told apart from them when deserializing
+    #[cfg_attr(feature = "serde", serde(skip_deserializing))]
+    Signal(Signal),
+    #[cfg_attr(feature = "serde", serde(skip_deserializing))]
+    Errno(Errno),
+    #[cfg_attr(feature = "serde", serde(skip_deserializing))]
+    Arch(AuditArch),
+    #[cfg_attr(feature = "serde", serde(skip_deserializing))]
+    Mode(Mode),
+    /// Socket address, along with the name of its family as styled with
+    /// [`InterpretOptions::name_style`](crate::InterpretOptions::name_style)
+    #[cfg_attr(
+        feature = "serde",
+        serde(
+            skip_deserializing,
+            serialize_with = "crate::interpret::serialize_socket_addr"
+        )
+    )]
+    SocketAddr {
+        address: SocketAddr,
+        family: String,
+    },
+    #[cfg_attr(feature = "serde", serde(skip_deserializing))]
+    MacLabel(MacLabel),
+    #[cfg_attr(feature = "serde", serde(skip_deserializing))]
+    AuditFlag(AuditFlag),
+    #[cfg_attr(feature = "serde", serde(skip_deserializing))]
+    Result(AuditResult),
 }
 
 impl From<Number> for FieldValue {
@@ -70,6 +193,12 @@ impl From<bool> for FieldValue {
     }
 }
 
+impl From<Cow<'_, str>> for FieldValue {
+    fn from(value: Cow<'_, str>) -> Self {
+        Self::String(value.into_owned())
+    }
+}
+
 impl From<&str> for FieldValue {
     fn from(value: &str) -> Self {
         Self::String(value.to_string())
@@ -82,9 +211,25 @@ impl From<Vec<String>> for FieldValue {
     }
 }
 
+/// Uses the kernel name of the family, such as `AF_INET`
+impl From<SocketAddr> for FieldValue {
+    fn from(value: SocketAddr) -> Self {
+        Self::SocketAddr {
+            family: value.family().to_string(),
+            address: value,
+        }
+    }
+}
+
+impl From<Fields<FieldValue>> for FieldValue {
+    fn from(value: Fields<FieldValue>) -> Self {
+        Self::Map(value)
+    }
+}
+
 impl From<BTreeMap<String, FieldValue>> for FieldValue {
     fn from(value: BTreeMap<String, FieldValue>) -> Self {
-        Self::Map(value)
+        Self::Map(value.into())
     }
 }
 
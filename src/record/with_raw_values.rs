@@ -0,0 +1,65 @@
+use std::collections::HashMap;
+
+use serde::{Serialize, Serializer, ser::SerializeMap};
+
+use super::with_timestamp_format::RecordView;
+use crate::{AuditdRecord, FieldValue, Fields, TimestampFormat};
+
+/// Record serialized with every field as `{"raw": ..., "value": ...}`,
+/// created with [`AuditdRecord::with_raw_values`].
+///
+/// Only top-level fields have a raw value: the sub-fields of `msg` are interpreted from
+/// the raw value of `msg`, and the enrichment is serialized the same as [`AuditdRecord`],
+/// like the rest of the record.
+#[derive(Debug, Clone, Copy, Serialize)]
+#[serde(transparent)]
+pub struct WithRawValues<'a>(RecordView<'a, FieldsWithRawValues<'a>>);
+
+impl<'a> WithRawValues<'a> {
+    pub(super) fn new(record: &'a AuditdRecord) -> Self {
+        let fields = FieldsWithRawValues {
+            fields: &record.fields,
+            raw_fields: &record.raw_fields,
+        };
+        Self(RecordView::new(record, fields, TimestampFormat::default()))
+    }
+
+    /// Serializes the timestamp with the given format.
+    #[must_use]
+    pub fn timestamp_format(self, format: TimestampFormat) -> Self {
+        Self(self.0.timestamp_format(format))
+    }
+}
+
+#[derive(Debug, Clone, Copy)]
+struct FieldsWithRawValues<'a> {
+    fields: &'a Fields<FieldValue>,
+    raw_fields: &'a Fields<String>,
+}
+
+#[derive(Serialize)]
+struct FieldWithRawValue<'a> {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    raw: Option<&'a str>,
+    value: &'a FieldValue,
+}
+
+impl Serialize for FieldsWithRawValues<'_> {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        // Duplicated keys are matched with the raw value of the same occurrence
+        let mut occurrences = HashMap::<&str, usize>::new();
+        let mut map = serializer.serialize_map(Some(self.fields.len()))?;
+        for (key, value) in self.fields.iter() {
+            let occurrence = occurrences.entry(key).or_default();
+            let raw = self.raw_fields.get_all(key).nth(*occurrence);
+            *occurrence += 1;
+
+            let field = FieldWithRawValue {
+                raw: raw.map(String::as_str),
+                value,
+            };
+            map.serialize_entry(key, &field)?;
+        }
+        map.end()
+    }
+}
@@ -0,0 +1,103 @@
+use serde::{Serialize, Serializer};
+
+use crate::timestamp::FormattedTimestamp;
+use crate::{
+    AuditEvent, AuditTimestamp, AuditdRecord, FieldValue, Fields, ParserError, RecordType,
+    TimestampFormat,
+};
+
+/// Record or event serialized with its timestamps in the given format, created with
+/// [`AuditdRecord::with_timestamp_format`] or [`AuditEvent::with_timestamp_format`].
+///
+/// The rest of the record or event is serialized the same.
+#[derive(Debug, Clone, Copy)]
+pub struct WithTimestampFormat<'a, T> {
+    value: &'a T,
+    format: TimestampFormat,
+}
+
+impl<'a, T> WithTimestampFormat<'a, T> {
+    pub(crate) fn new(value: &'a T, format: TimestampFormat) -> Self {
+        Self { value, format }
+    }
+}
+
+impl Serialize for WithTimestampFormat<'_, AuditdRecord> {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        RecordView::new(self.value, &self.value.fields, self.format).serialize(serializer)
+    }
+}
+
+#[derive(Serialize)]
+struct EventView<'a> {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    node: Option<&'a str>,
+    timestamp: Option<FormattedTimestamp>,
+    id: Option<u64>,
+    records: Vec<WithTimestampFormat<'a, AuditdRecord>>,
+}
+
+impl Serialize for WithTimestampFormat<'_, AuditEvent> {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        let event = self.value;
+        EventView {
+            node: event.node.as_deref(),
+            timestamp: format_timestamp(event.timestamp, self.format),
+            id: event.id,
+            records: event
+                .records
+                .iter()
+                .map(|record| WithTimestampFormat::new(record, self.format))
+                .collect(),
+        }
+        .serialize(serializer)
+    }
+}
+
+/// Record serialized with the given fields and timestamp format, mirroring the serialization
+/// of [`AuditdRecord`].
+#[derive(Debug, Clone, Copy, Serialize)]
+pub(super) struct RecordView<'a, F> {
+    record_type: &'a RecordType,
+    timestamp: Option<FormattedTimestamp>,
+    id: Option<u64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    node: Option<&'a str>,
+    fields: F,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    enrichment: Option<&'a Fields<FieldValue>>,
+    #[serde(skip_serializing_if = "<[_]>::is_empty")]
+    unparsed: &'a [String],
+    #[serde(skip_serializing_if = "<[_]>::is_empty")]
+    warnings: &'a [ParserError],
+}
+
+impl<'a, F> RecordView<'a, F> {
+    pub(super) fn new(record: &'a AuditdRecord, fields: F, format: TimestampFormat) -> Self {
+        Self {
+            record_type: &record.record_type,
+            timestamp: format_timestamp(record.timestamp, format),
+            id: record.id,
+            node: record.node.as_deref(),
+            fields,
+            enrichment: record.enrichment.as_ref(),
+            unparsed: &record.unparsed,
+            warnings: &record.warnings,
+        }
+    }
+
+    pub(super) fn timestamp_format(mut self, format: TimestampFormat) -> Self {
+        self.timestamp = self.timestamp.map(|timestamp| FormattedTimestamp {
+            format,
+            ..timestamp
+        });
+        self
+    }
+}
+
+fn format_timestamp(
+    timestamp: Option<AuditTimestamp>,
+    format: TimestampFormat,
+) -> Option<FormattedTimestamp> {
+    timestamp.map(|timestamp| FormattedTimestamp { timestamp, format })
+}
@@ -0,0 +1,423 @@
+use std::convert::Infallible;
+use std::fmt::{self, Display, Formatter};
+use std::str::FromStr;
+
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+
+/// Defines the [`RecordType`] enum along with its name and number conversions
+/// from a single `Variant => "NAME" = number` table.
+macro_rules! record_types {
+    ($($variant:ident => $name:literal = $number:literal,)*) => {
+        /// Type of an auditd record, as found in the `type=` part of the header.
+        ///
+        /// Record types may be logged by name (`type=SYSCALL`), by number (`type=1300`)
+        /// or, when auditd does not know their name, as `type=UNKNOWN[1300]`.
+        /// All of them are resolved to the same variant whenever the number is known.
+        #[derive(Debug, Clone, PartialEq, Eq, Hash)]
+        pub enum RecordType {
+            $($variant,)*
+            /// Record type number that is not known by this crate, displayed as `UNKNOWN[number]`
+            Unknown(u32),
+            /// Record type name that is not known by this crate
+            Other(String),
+        }
+
+        impl RecordType {
+            /// Resolves the record type of a message number, falling back to [`RecordType::Unknown`].
+            pub fn from_number(number: u32) -> Self {
+                match number {
+                    $($number => Self::$variant,)*
+                    _ => Self::Unknown(number),
+                }
+            }
+
+            /// Resolves the record type of a name, such as `SYSCALL`.
+            pub fn from_name(name: &str) -> Option<Self> {
+                match name {
+                    $($name => Some(Self::$variant),)*
+                    _ => None,
+                }
+            }
+
+            /// Message number of the record type, if known.
+            pub fn number(&self) -> Option<u32> {
+                match self {
+                    $(Self::$variant => Some($number),)*
+                    Self::Unknown(number) => Some(*number),
+                    Self::Other(_) => None,
+                }
+            }
+
+            /// Name of the record type, if known.
+            pub fn name(&self) -> Option<&str> {
+                match self {
+                    $(Self::$variant => Some($name),)*
+                    Self::Unknown(_) => None,
+                    Self::Other(name) => Some(name),
+                }
+            }
+        }
+    };
+}
+
+// Kernel message types are extracted from https://github.com/torvalds/linux/blob/561c80369df0733ba0574882a1635287b20f9de2/include/uapi/linux/audit.h
+// Userspace message types are extracted from https://github.com/linux-audit/audit-userspace/blob/747f67994b933fd70deed7d6f7cb0c40601f5bd1/lib/libaudit.h
+record_types! {
+    // Kernel control messages (1000 - 1099)
+    Get => "GET" = 1000,
+    Set => "SET" = 1001,
+    List => "LIST" = 1002,
+    Add => "ADD" = 1003,
+    Del => "DEL" = 1004,
+    User => "USER" = 1005,
+    Login => "LOGIN" = 1006,
+    WatchIns => "WATCH_INS" = 1007,
+    WatchRem => "WATCH_REM" = 1008,
+    WatchList => "WATCH_LIST" = 1009,
+    SignalInfo => "SIGNAL_INFO" = 1010,
+    AddRule => "ADD_RULE" = 1011,
+    DelRule => "DEL_RULE" = 1012,
+    ListRules => "LIST_RULES" = 1013,
+    Trim => "TRIM" = 1014,
+    MakeEquiv => "MAKE_EQUIV" = 1015,
+    TtyGet => "TTY_GET" = 1016,
+    TtySet => "TTY_SET" = 1017,
+    SetFeature => "SET_FEATURE" = 1018,
+    GetFeature => "GET_FEATURE" = 1019,
+
+    // Userspace messages (1100 - 1199)
+    UserAuth => "USER_AUTH" = 1100,
+    UserAcct => "USER_ACCT" = 1101,
+    UserMgmt => "USER_MGMT" = 1102,
+    CredAcq => "CRED_ACQ" = 1103,
+    CredDisp => "CRED_DISP" = 1104,
+    UserStart => "USER_START" = 1105,
+    UserEnd => "USER_END" = 1106,
+    UserAvc => "USER_AVC" = 1107,
+    UserChauthtok => "USER_CHAUTHTOK" = 1108,
+    UserErr => "USER_ERR" = 1109,
+    CredRefr => "CRED_REFR" = 1110,
+    UsysConfig => "USYS_CONFIG" = 1111,
+    UserLogin => "USER_LOGIN" = 1112,
+    UserLogout => "USER_LOGOUT" = 1113,
+    AddUser => "ADD_USER" = 1114,
+    DelUser => "DEL_USER" = 1115,
+    AddGroup => "ADD_GROUP" = 1116,
+    DelGroup => "DEL_GROUP" = 1117,
+    DacCheck => "DAC_CHECK" = 1118,
+    ChgrpId => "CHGRP_ID" = 1119,
+    Test => "TEST" = 1120,
+    TrustedApp => "TRUSTED_APP" = 1121,
+    UserSelinuxErr => "USER_SELINUX_ERR" = 1122,
+    UserCmd => "USER_CMD" = 1123,
+    UserTty => "USER_TTY" = 1124,
+    ChuserId => "CHUSER_ID" = 1125,
+    GrpAuth => "GRP_AUTH" = 1126,
+    SystemBoot => "SYSTEM_BOOT" = 1127,
+    SystemShutdown => "SYSTEM_SHUTDOWN" = 1128,
+    SystemRunlevel => "SYSTEM_RUNLEVEL" = 1129,
+    ServiceStart => "SERVICE_START" = 1130,
+    ServiceStop => "SERVICE_STOP" = 1131,
+    GrpMgmt => "GRP_MGMT" = 1132,
+    GrpChauthtok => "GRP_CHAUTHTOK" = 1133,
+    MacCheck => "MAC_CHECK" = 1134,
+    AcctLock => "ACCT_LOCK" = 1135,
+    AcctUnlock => "ACCT_UNLOCK" = 1136,
+    UserDevice => "USER_DEVICE" = 1137,
+    SoftwareUpdate => "SOFTWARE_UPDATE" = 1138,
+
+    // Audit daemon messages (1200 - 1299)
+    DaemonStart => "DAEMON_START" = 1200,
+    DaemonEnd => "DAEMON_END" = 1201,
+    DaemonAbort => "DAEMON_ABORT" = 1202,
+    DaemonConfig => "DAEMON_CONFIG" = 1203,
+    DaemonReconfig => "DAEMON_RECONFIG" = 1204,
+    DaemonRotate => "DAEMON_ROTATE" = 1205,
+    DaemonResume => "DAEMON_RESUME" = 1206,
+    DaemonAccept => "DAEMON_ACCEPT" = 1207,
+    DaemonClose => "DAEMON_CLOSE" = 1208,
+    DaemonErr => "DAEMON_ERR" = 1209,
+
+    // Kernel event messages (1300 - 1399)
+    Syscall => "SYSCALL" = 1300,
+    FsWatch => "FS_WATCH" = 1301,
+    Path => "PATH" = 1302,
+    Ipc => "IPC" = 1303,
+    Socketcall => "SOCKETCALL" = 1304,
+    ConfigChange => "CONFIG_CHANGE" = 1305,
+    Sockaddr => "SOCKADDR" = 1306,
+    Cwd => "CWD" = 1307,
+    Execve => "EXECVE" = 1309,
+    IpcSetPerm => "IPC_SET_PERM" = 1311,
+    MqOpen => "MQ_OPEN" = 1312,
+    MqSendrecv => "MQ_SENDRECV" = 1313,
+    MqNotify => "MQ_NOTIFY" = 1314,
+    MqGetsetattr => "MQ_GETSETATTR" = 1315,
+    KernelOther => "KERNEL_OTHER" = 1316,
+    FdPair => "FD_PAIR" = 1317,
+    ObjPid => "OBJ_PID" = 1318,
+    Tty => "TTY" = 1319,
+    Eoe => "EOE" = 1320,
+    BprmFcaps => "BPRM_FCAPS" = 1321,
+    Capset => "CAPSET" = 1322,
+    Mmap => "MMAP" = 1323,
+    NetfilterPkt => "NETFILTER_PKT" = 1324,
+    NetfilterCfg => "NETFILTER_CFG" = 1325,
+    Seccomp => "SECCOMP" = 1326,
+    Proctitle => "PROCTITLE" = 1327,
+    FeatureChange => "FEATURE_CHANGE" = 1328,
+    Replace => "REPLACE" = 1329,
+    KernModule => "KERN_MODULE" = 1330,
+    Fanotify => "FANOTIFY" = 1331,
+    TimeInjoffset => "TIME_INJOFFSET" = 1332,
+    TimeAdjntpval => "TIME_ADJNTPVAL" = 1333,
+    Bpf => "BPF" = 1334,
+    EventListener => "EVENT_LISTENER" = 1335,
+    Uringop => "URINGOP" = 1336,
+    Openat2 => "OPENAT2" = 1337,
+    DmCtrl => "DM_CTRL" = 1338,
+    DmEvent => "DM_EVENT" = 1339,
+
+    // SELinux and other LSM messages (1400 - 1499)
+    Avc => "AVC" = 1400,
+    SelinuxErr => "SELINUX_ERR" = 1401,
+    AvcPath => "AVC_PATH" = 1402,
+    MacPolicyLoad => "MAC_POLICY_LOAD" = 1403,
+    MacStatus => "MAC_STATUS" = 1404,
+    MacConfigChange => "MAC_CONFIG_CHANGE" = 1405,
+    MacUnlblAllow => "MAC_UNLBL_ALLOW" = 1406,
+    MacCipsov4Add => "MAC_CIPSOV4_ADD" = 1407,
+    MacCipsov4Del => "MAC_CIPSOV4_DEL" = 1408,
+    MacMapAdd => "MAC_MAP_ADD" = 1409,
+    MacMapDel => "MAC_MAP_DEL" = 1410,
+    MacIpsecAddsa => "MAC_IPSEC_ADDSA" = 1411,
+    MacIpsecDelsa => "MAC_IPSEC_DELSA" = 1412,
+    MacIpsecAddspd => "MAC_IPSEC_ADDSPD" = 1413,
+    MacIpsecDelspd => "MAC_IPSEC_DELSPD" = 1414,
+    MacIpsecEvent => "MAC_IPSEC_EVENT" = 1415,
+    MacUnlblStcadd => "MAC_UNLBL_STCADD" = 1416,
+    MacUnlblStcdel => "MAC_UNLBL_STCDEL" = 1417,
+    MacCalipsoAdd => "MAC_CALIPSO_ADD" = 1418,
+    MacCalipsoDel => "MAC_CALIPSO_DEL" = 1419,
+    IpeAccess => "IPE_ACCESS" = 1420,
+    IpeConfigChange => "IPE_CONFIG_CHANGE" = 1421,
+    IpePolicyLoad => "IPE_POLICY_LOAD" = 1422,
+
+    // AppArmor messages (1500 - 1599)
+    Aa => "AA" = 1500,
+    ApparmorAudit => "APPARMOR_AUDIT" = 1501,
+    ApparmorAllowed => "APPARMOR_ALLOWED" = 1502,
+    ApparmorDenied => "APPARMOR_DENIED" = 1503,
+    ApparmorHint => "APPARMOR_HINT" = 1504,
+    ApparmorStatus => "APPARMOR_STATUS" = 1505,
+    ApparmorError => "APPARMOR_ERROR" = 1506,
+    ApparmorKill => "APPARMOR_KILL" = 1507,
+
+    // Kernel anomaly messages (1700 - 1799)
+    AnomPromiscuous => "ANOM_PROMISCUOUS" = 1700,
+    AnomAbend => "ANOM_ABEND" = 1701,
+    AnomLink => "ANOM_LINK" = 1702,
+    AnomCreat => "ANOM_CREAT" = 1703,
+
+    // Kernel integrity messages (1800 - 1899)
+    IntegrityData => "INTEGRITY_DATA" = 1800,
+    IntegrityMetadata => "INTEGRITY_METADATA" = 1801,
+    IntegrityStatus => "INTEGRITY_STATUS" = 1802,
+    IntegrityHash => "INTEGRITY_HASH" = 1803,
+    IntegrityPcr => "INTEGRITY_PCR" = 1804,
+    IntegrityRule => "INTEGRITY_RULE" = 1805,
+    IntegrityEvmXattr => "INTEGRITY_EVM_XATTR" = 1806,
+    IntegrityPolicyRule => "INTEGRITY_POLICY_RULE" = 1807,
+
+    Kernel => "KERNEL" = 2000,
+
+    // Userspace anomaly messages (2100 - 2199)
+    AnomLoginFailures => "ANOM_LOGIN_FAILURES" = 2100,
+    AnomLoginTime => "ANOM_LOGIN_TIME" = 2101,
+    AnomLoginSessions => "ANOM_LOGIN_SESSIONS" = 2102,
+    AnomLoginAcct => "ANOM_LOGIN_ACCT" = 2103,
+    AnomLoginLocation => "ANOM_LOGIN_LOCATION" = 2104,
+    AnomMaxDac => "ANOM_MAX_DAC" = 2105,
+    AnomMaxMac => "ANOM_MAX_MAC" = 2106,
+    AnomAmtuFail => "ANOM_AMTU_FAIL" = 2107,
+    AnomRbacFail => "ANOM_RBAC_FAIL" = 2108,
+    AnomRbacIntegrityFail => "ANOM_RBAC_INTEGRITY_FAIL" = 2109,
+    AnomCryptoFail => "ANOM_CRYPTO_FAIL" = 2110,
+    AnomAccessFs => "ANOM_ACCESS_FS" = 2111,
+    AnomExec => "ANOM_EXEC" = 2112,
+    AnomMkExec => "ANOM_MK_EXEC" = 2113,
+    AnomAddAcct => "ANOM_ADD_ACCT" = 2114,
+    AnomDelAcct => "ANOM_DEL_ACCT" = 2115,
+    AnomModAcct => "ANOM_MOD_ACCT" = 2116,
+    AnomRootTrans => "ANOM_ROOT_TRANS" = 2117,
+    AnomLoginService => "ANOM_LOGIN_SERVICE" = 2118,
+    AnomLoginRoot => "ANOM_LOGIN_ROOT" = 2119,
+    AnomOriginFailures => "ANOM_ORIGIN_FAILURES" = 2120,
+    AnomSession => "ANOM_SESSION" = 2121,
+
+    // Anomaly response messages (2200 - 2299)
+    RespAnomaly => "RESP_ANOMALY" = 2200,
+    RespAlert => "RESP_ALERT" = 2201,
+    RespKillProc => "RESP_KILL_PROC" = 2202,
+    RespTermAccess => "RESP_TERM_ACCESS" = 2203,
+    RespAcctRemote => "RESP_ACCT_REMOTE" = 2204,
+    RespAcctLockTimed => "RESP_ACCT_LOCK_TIMED" = 2205,
+    RespAcctUnlockTimed => "RESP_ACCT_UNLOCK_TIMED" = 2206,
+    RespAcctLock => "RESP_ACCT_LOCK" = 2207,
+    RespTermLock => "RESP_TERM_LOCK" = 2208,
+    RespSebool => "RESP_SEBOOL" = 2209,
+    RespExec => "RESP_EXEC" = 2210,
+    RespSingle => "RESP_SINGLE" = 2211,
+    RespHalt => "RESP_HALT" = 2212,
+    RespOriginBlock => "RESP_ORIGIN_BLOCK" = 2213,
+    RespOriginBlockTimed => "RESP_ORIGIN_BLOCK_TIMED" = 2214,
+    RespOriginUnblockTimed => "RESP_ORIGIN_UNBLOCK_TIMED" = 2215,
+
+    // Userspace role based access control messages (2300 - 2399)
+    UserRoleChange => "USER_ROLE_CHANGE" = 2300,
+    RoleAssign => "ROLE_ASSIGN" = 2301,
+    RoleRemove => "ROLE_REMOVE" = 2302,
+    LabelOverride => "LABEL_OVERRIDE" = 2303,
+    LabelLevelChange => "LABEL_LEVEL_CHANGE" = 2304,
+    UserLabeledExport => "USER_LABELED_EXPORT" = 2305,
+    UserUnlabeledExport => "USER_UNLABELED_EXPORT" = 2306,
+    DevAlloc => "DEV_ALLOC" = 2307,
+    DevDealloc => "DEV_DEALLOC" = 2308,
+    FsRelabel => "FS_RELABEL" = 2309,
+    UserMacPolicyLoad => "USER_MAC_POLICY_LOAD" = 2310,
+    RoleModify => "ROLE_MODIFY" = 2311,
+    UserMacConfigChange => "USER_MAC_CONFIG_CHANGE" = 2312,
+    UserMacStatus => "USER_MAC_STATUS" = 2313,
+
+    // Userspace crypto messages (2400 - 2499)
+    CryptoTestUser => "CRYPTO_TEST_USER" = 2400,
+    CryptoParamChangeUser => "CRYPTO_PARAM_CHANGE_USER" = 2401,
+    CryptoLogin => "CRYPTO_LOGIN" = 2402,
+    CryptoLogout => "CRYPTO_LOGOUT" = 2403,
+    CryptoKeyUser => "CRYPTO_KEY_USER" = 2404,
+    CryptoFailureUser => "CRYPTO_FAILURE_USER" = 2405,
+    CryptoReplayUser => "CRYPTO_REPLAY_USER" = 2406,
+    CryptoSession => "CRYPTO_SESSION" = 2407,
+    CryptoIkeSa => "CRYPTO_IKE_SA" = 2408,
+    CryptoIpsecSa => "CRYPTO_IPSEC_SA" = 2409,
+
+    // Userspace virtualization messages (2500 - 2599)
+    VirtControl => "VIRT_CONTROL" = 2500,
+    VirtResource => "VIRT_RESOURCE" = 2501,
+    VirtMachineId => "VIRT_MACHINE_ID" = 2502,
+    VirtIntegrityCheck => "VIRT_INTEGRITY_CHECK" = 2503,
+    VirtCreate => "VIRT_CREATE" = 2504,
+    VirtDestroy => "VIRT_DESTROY" = 2505,
+    VirtMigrateIn => "VIRT_MIGRATE_IN" = 2506,
+    VirtMigrateOut => "VIRT_MIGRATE_OUT" = 2507,
+}
+
+/// Parses the `UNKNOWN[1234]` form that auditd uses for message numbers it does not know the name of.
+fn parse_unknown_number(input: &str) -> Option<u32> {
+    input
+        .strip_prefix("UNKNOWN[")?
+        .strip_suffix(']')?
+        .parse()
+        .ok()
+}
+
+impl From<u32> for RecordType {
+    fn from(value: u32) -> Self {
+        Self::from_number(value)
+    }
+}
+
+impl From<&str> for RecordType {
+    fn from(value: &str) -> Self {
+        if let Some(record_type) = Self::from_name(value) {
+            return record_type;
+        }
+
+        match value.parse().ok().or_else(|| parse_unknown_number(value)) {
+            Some(number) => Self::from_number(number),
+            None => Self::Other(value.to_string()),
+        }
+    }
+}
+
+impl FromStr for RecordType {
+    type Err = Infallible;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Ok(Self::from(s))
+    }
+}
+
+impl Display for RecordType {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Unknown(number) => write!(f, "UNKNOWN[{number}]"),
+            _ => write!(f, "{}", self.name().unwrap_or_default()),
+        }
+    }
+}
+
+#[cfg(feature = "serde")]
+impl Serialize for RecordType {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.collect_str(self)
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<'de> Deserialize<'de> for RecordType {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let value = String::deserialize(deserializer)?;
+        Ok(Self::from(value.as_str()))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rstest::rstest;
+
+    #[rstest]
+    #[case::name("SYSCALL", RecordType::Syscall)]
+    #[case::userspace_name("CRYPTO_IKE_SA", RecordType::CryptoIkeSa)]
+    #[case::number("1327", RecordType::Proctitle)]
+    #[case::unknown_known_number("UNKNOWN[1329]", RecordType::Replace)]
+    #[case::unknown_number("UNKNOWN[9999]", RecordType::Unknown(9999))]
+    #[case::number_not_in_table("9999", RecordType::Unknown(9999))]
+    #[case::other("FOO", RecordType::Other("FOO".into()))]
+    #[case::lowercase("syscall", RecordType::Other("syscall".into()))]
+    #[case::malformed_unknown("UNKNOWN[abc]", RecordType::Other("UNKNOWN[abc]".into()))]
+    #[case::empty("", RecordType::Other(String::new()))]
+    fn test_record_type_from_str(#[case] input: &str, #[case] expected: RecordType) {
+        assert_eq!(RecordType::from(input), expected);
+    }
+
+    #[rstest]
+    #[case::known(RecordType::Syscall, "SYSCALL")]
+    #[case::unknown(RecordType::Unknown(9999), "UNKNOWN[9999]")]
+    #[case::other(RecordType::Other("FOO".into()), "FOO")]
+    fn test_record_type_display(#[case] input: RecordType, #[case] expected: &str) {
+        assert_eq!(input.to_string(), expected);
+    }
+
+    #[rstest]
+    #[case::known(RecordType::Execve, Some(1309))]
+    #[case::unknown(RecordType::Unknown(9999), Some(9999))]
+    #[case::other(RecordType::Other("FOO".into()), None)]
+    fn test_record_type_number(#[case] input: RecordType, #[case] expected: Option<u32>) {
+        assert_eq!(input.number(), expected);
+    }
+
+    #[test]
+    fn test_record_type_number_roundtrip() {
+        for number in 1000..3000 {
+            let record_type = RecordType::from_number(number);
+            assert_eq!(record_type.number(), Some(number));
+            assert_eq!(
+                RecordType::from(record_type.to_string().as_str()),
+                record_type
+            );
+        }
+    }
+}
@@ -0,0 +1,604 @@
+use std::fmt::{self, Display};
+use std::str::FromStr;
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+
+use nom::branch::alt;
+use nom::bytes::complete::take_while_m_n;
+use nom::character::complete::{char, digit1, one_of, u64};
+use nom::combinator::{all_consuming, map_res, opt};
+use nom::sequence::{preceded, separated_pair};
+use nom::{Finish, IResult, Parser};
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Deserializer, Serialize, Serializer, de::Visitor};
+use thiserror::Error;
+
+const NANOSECONDS_PER_SECOND: u32 = 1_000_000_000;
+const NANOSECONDS_PER_MILLISECOND: u32 = 1_000_000;
+const SECONDS_PER_DAY: u32 = 86_400;
+
+/// Timestamp of a record, as logged in the `msg=audit(1234.567:89)` part of its header.
+///
+/// When serialized, it is represented as the seconds since the Unix epoch with their fraction,
+/// as logged (e.g. `"1725039526.208"`), so no precision is lost. Other representations can be
+/// chosen with [`TimestampFormat`], or with the [`rfc3339`] module for a single field.
+/// Every representation is accepted when deserializing.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct AuditTimestamp {
+    seconds: u64,
+    nanoseconds: u32,
+}
+
+impl AuditTimestamp {
+    /// Creates a timestamp from the seconds and nanoseconds since the Unix epoch,
+    /// returning `None` if the nanoseconds are not less than a second.
+    #[must_use]
+    pub fn new(seconds: u64, nanoseconds: u32) -> Option<Self> {
+        (nanoseconds < NANOSECONDS_PER_SECOND).then_some(Self {
+            seconds,
+            nanoseconds,
+        })
+    }
+
+    /// Creates a timestamp from the milliseconds since the Unix epoch.
+    #[must_use]
+    pub fn from_millis(milliseconds: u64) -> Self {
+        Self {
+            seconds: milliseconds / 1000,
+            nanoseconds: (milliseconds % 1000) as u32 * NANOSECONDS_PER_MILLISECOND,
+        }
+    }
+
+    /// Seconds since the Unix epoch.
+    #[must_use]
+    pub fn seconds(&self) -> u64 {
+        self.seconds
+    }
+
+    /// Nanoseconds of the fractional part of the timestamp.
+    #[must_use]
+    pub fn subsec_nanos(&self) -> u32 {
+        self.nanoseconds
+    }
+
+    /// Milliseconds since the Unix epoch, truncating the sub-millisecond precision.
+    #[must_use]
+    pub fn as_millis(&self) -> u64 {
+        self.seconds
+            .saturating_mul(1000)
+            .saturating_add(u64::from(self.nanoseconds / NANOSECONDS_PER_MILLISECOND))
+    }
+
+    /// Nanoseconds since the Unix epoch.
+    #[must_use]
+    pub fn as_nanos(&self) -> u128 {
+        self.duration_since_epoch().as_nanos()
+    }
+
+    #[must_use]
+    pub fn duration_since_epoch(&self) -> Duration {
+        Duration::new(self.seconds, self.nanoseconds)
+    }
+
+    /// Returns `None` if the timestamp can not be represented by the platform.
+    #[must_use]
+    pub fn to_system_time(&self) -> Option<SystemTime> {
+        UNIX_EPOCH.checked_add(self.duration_since_epoch())
+    }
+
+    /// Returns `None` if the timestamp is out of the range supported by [`chrono`].
+    #[cfg(feature = "chrono")]
+    #[must_use]
+    pub fn to_chrono(&self) -> Option<chrono::DateTime<chrono::Utc>> {
+        chrono::DateTime::from_timestamp(i64::try_from(self.seconds).ok()?, self.nanoseconds)
+    }
+
+    /// Returns `None` if the timestamp is out of the range supported by [`jiff`].
+    #[cfg(feature = "jiff")]
+    #[must_use]
+    pub fn to_jiff(&self) -> Option<jiff::Timestamp> {
+        let nanoseconds = i32::try_from(self.nanoseconds).ok()?;
+        jiff::Timestamp::new(i64::try_from(self.seconds).ok()?, nanoseconds).ok()
+    }
+
+    /// Returns `None` if the timestamp is out of the range supported by [`time`].
+    #[cfg(feature = "time")]
+    #[must_use]
+    pub fn to_time(&self) -> Option<time::OffsetDateTime> {
+        let nanoseconds = i128::try_from(self.as_nanos()).ok()?;
+        time::OffsetDateTime::from_unix_timestamp_nanos(nanoseconds).ok()
+    }
+}
+
+impl From<AuditTimestamp> for Duration {
+    fn from(value: AuditTimestamp) -> Self {
+        value.duration_since_epoch()
+    }
+}
+
+/// Formats the timestamp as an RFC 3339 string in UTC, with as many fractional digits
+/// as needed for its precision (but at least milliseconds, as logged by auditd).
+impl Display for AuditTimestamp {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let days = self.seconds / u64::from(SECONDS_PER_DAY);
+        let seconds_of_day = self.seconds % u64::from(SECONDS_PER_DAY);
+        // `u64::MAX` seconds are far less than `i64::MAX` days, so this never fails
+        let (year, month, day) = civil_from_days(i64::try_from(days).map_err(|_| fmt::Error)?);
+
+        write!(
+            f,
+            "{year:04}-{month:02}-{day:02}T{:02}:{:02}:{:02}",
+            seconds_of_day / 3600,
+            seconds_of_day % 3600 / 60,
+            seconds_of_day % 60
+        )?;
+
+        write_fraction(f, self.nanoseconds)?;
+        f.write_str("Z")
+    }
+}
+
+/// Writes the fraction of a second with as many digits as needed for its precision,
+/// but at least milliseconds, as logged by auditd.
+fn write_fraction(f: &mut fmt::Formatter<'_>, nanoseconds: u32) -> fmt::Result {
+    match nanoseconds {
+        nanoseconds if nanoseconds % NANOSECONDS_PER_MILLISECOND == 0 => {
+            write!(f, ".{:03}", nanoseconds / NANOSECONDS_PER_MILLISECOND)
+        }
+        nanoseconds if nanoseconds % 1000 == 0 => write!(f, ".{:06}", nanoseconds / 1000),
+        nanoseconds => write!(f, ".{nanoseconds:09}"),
+    }
+}
+
+/// Formats a timestamp as the seconds since the Unix epoch with their fraction, such as
+/// `1725039526.208`, as it is logged.
+struct UnixSeconds(AuditTimestamp);
+
+impl Display for UnixSeconds {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.0.seconds)?;
+        write_fraction(f, self.0.nanoseconds)
+    }
+}
+
+/// Error returned when parsing an [`AuditTimestamp`] from an RFC 3339 string fails.
+#[derive(Debug, Clone, PartialEq, Eq, Error)]
+#[error("invalid RFC 3339 timestamp: {0:?}")]
+pub struct ParseTimestampError(String);
+
+/// Parses an RFC 3339 timestamp, such as `2024-08-30T17:38:46.208Z`.
+impl FromStr for AuditTimestamp {
+    type Err = ParseTimestampError;
+
+    fn from_str(input: &str) -> Result<Self, Self::Err> {
+        all_consuming(parse_rfc3339)
+            .parse(input)
+            .finish()
+            .ok()
+            .and_then(|(_, timestamp)| timestamp)
+            .ok_or_else(|| ParseTimestampError(input.to_string()))
+    }
+}
+
+#[cfg(feature = "serde")]
+impl Serialize for AuditTimestamp {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.collect_str(&UnixSeconds(*self))
+    }
+}
+
+/// Representation of the timestamps when serializing records and events, chosen with
+/// [`AuditdRecord::with_timestamp_format`](crate::AuditdRecord::with_timestamp_format)
+/// or [`AuditEvent::with_timestamp_format`](crate::AuditEvent::with_timestamp_format).
+#[cfg(feature = "serde")]
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum TimestampFormat {
+    /// Seconds since the Unix epoch with their fraction, as logged (e.g. `"1725039526.208"`)
+    #[default]
+    UnixSeconds,
+    /// Unix timestamp in milliseconds (e.g. `1725039526208`), truncating the sub-millisecond
+    /// precision
+    UnixMillis,
+    /// RFC 3339 string in UTC (e.g. `"2024-08-30T17:38:46.208Z"`)
+    Rfc3339,
+}
+
+/// Timestamp serialized with the given format.
+#[cfg(feature = "serde")]
+#[derive(Debug, Clone, Copy)]
+pub(crate) struct FormattedTimestamp {
+    pub(crate) timestamp: AuditTimestamp,
+    pub(crate) format: TimestampFormat,
+}
+
+#[cfg(feature = "serde")]
+impl Serialize for FormattedTimestamp {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        match self.format {
+            TimestampFormat::UnixSeconds => self.timestamp.serialize(serializer),
+            TimestampFormat::UnixMillis => serializer.serialize_u64(self.timestamp.as_millis()),
+            TimestampFormat::Rfc3339 => serializer.collect_str(&self.timestamp),
+        }
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<'de> Deserialize<'de> for AuditTimestamp {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        struct AuditTimestampVisitor;
+
+        impl Visitor<'_> for AuditTimestampVisitor {
+            type Value = AuditTimestamp;
+
+            fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+                formatter
+                    .write_str("a Unix timestamp in seconds or milliseconds, or an RFC 3339 string")
+            }
+
+            fn visit_u64<E: serde::de::Error>(self, value: u64) -> Result<Self::Value, E> {
+                Ok(AuditTimestamp::from_millis(value))
+            }
+
+            fn visit_i64<E: serde::de::Error>(self, value: i64) -> Result<Self::Value, E> {
+                u64::try_from(value)
+                    .map(AuditTimestamp::from_millis)
+                    .map_err(|_| E::custom("timestamps before the Unix epoch are not supported"))
+            }
+
+            fn visit_str<E: serde::de::Error>(self, value: &str) -> Result<Self::Value, E> {
+                match all_consuming(parse_unix_seconds).parse(value).finish() {
+                    Ok((_, timestamp)) => Ok(timestamp),
+                    Err(_) => value.parse().map_err(E::custom),
+                }
+            }
+        }
+
+        deserializer.deserialize_any(AuditTimestampVisitor)
+    }
+}
+
+/// Serializes an [`AuditTimestamp`] as an RFC 3339 string in UTC, for fields that use
+/// `#[serde(with = "auditd_parser::rfc3339")]`.
+#[cfg(feature = "serde")]
+pub mod rfc3339 {
+    use serde::{Deserialize, Deserializer, Serializer};
+
+    use super::AuditTimestamp;
+
+    /// # Errors
+    ///
+    /// Returns the errors of the serializer.
+    pub fn serialize<S: Serializer>(
+        timestamp: &AuditTimestamp,
+        serializer: S,
+    ) -> Result<S::Ok, S::Error> {
+        serializer.collect_str(timestamp)
+    }
+
+    /// Accepts Unix timestamps in milliseconds too, as [`AuditTimestamp`] does.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the value is neither a valid RFC 3339 string nor a Unix timestamp.
+    pub fn deserialize<'de, D: Deserializer<'de>>(
+        deserializer: D,
+    ) -> Result<AuditTimestamp, D::Error> {
+        AuditTimestamp::deserialize(deserializer)
+    }
+}
+
+/// Parses a timestamp in `1234.567` format, where the whole part are seconds and the
+/// decimal part is the fraction of a second. auditd logs milliseconds, but any number
+/// of digits is accepted, up to nanosecond precision.
+pub(crate) fn parse_unix_seconds(input: &str) -> IResult<&str, AuditTimestamp> {
+    separated_pair(u64, char('.'), parse_fraction_nanoseconds)
+        .map_opt(|(seconds, nanoseconds)| AuditTimestamp::new(seconds, nanoseconds))
+        .parse(input)
+}
+
+/// Parses the fractional part of a timestamp as nanoseconds. Any number of digits
+/// is accepted, although the digits beyond nanoseconds are truncated.
+pub(crate) fn parse_fraction_nanoseconds(input: &str) -> IResult<&str, u32> {
+    digit1
+        .map(|digits: &str| {
+            digits
+                .bytes()
+                .chain(std::iter::repeat(b'0'))
+                .take(9)
+                .fold(0, |nanoseconds, digit| {
+                    nanoseconds * 10 + u32::from(digit - b'0')
+                })
+        })
+        .parse(input)
+}
+
+fn parse_number<T: FromStr>(digits: usize) -> impl FnMut(&str) -> IResult<&str, T> {
+    move |input| {
+        map_res(
+            take_while_m_n(digits, digits, |c: char| c.is_ascii_digit()),
+            str::parse,
+        )
+        .parse(input)
+    }
+}
+
+/// Parses the `Z` or `+01:00` offset of an RFC 3339 timestamp, as seconds east of UTC.
+fn parse_offset(input: &str) -> IResult<&str, i64> {
+    alt((
+        one_of("Zz").map(|_| 0),
+        (
+            one_of("+-"),
+            parse_number::<i64>(2),
+            char(':'),
+            parse_number::<i64>(2),
+        )
+            .map(|(sign, hours, _, minutes)| {
+                let offset = hours * 3600 + minutes * 60;
+                if sign == '-' { -offset } else { offset }
+            }),
+    ))
+    .parse(input)
+}
+
+/// Parses an RFC 3339 timestamp, returning `None` if it is not a valid date
+/// or if it is before the Unix epoch.
+fn parse_rfc3339(input: &str) -> IResult<&str, Option<AuditTimestamp>> {
+    (
+        (
+            parse_number::<i64>(4),
+            preceded(char('-'), parse_number::<u32>(2)),
+            preceded(char('-'), parse_number::<u32>(2)),
+        ),
+        preceded(
+            one_of("Tt "),
+            (
+                parse_number::<i64>(2),
+                preceded(char(':'), parse_number::<i64>(2)),
+                preceded(char(':'), parse_number::<i64>(2)),
+            ),
+        ),
+        opt(preceded(char('.'), parse_fraction_nanoseconds)),
+        parse_offset,
+    )
+        .map(
+            |((year, month, day), (hour, minute, second), nanoseconds, offset)| {
+                let valid = (1..=12).contains(&month)
+                && (1..=31).contains(&day)
+                && hour < 24
+                && minute < 60
+                // Leap seconds are represented as the 60th second
+                && second <= 60;
+                if !valid {
+                    return None;
+                }
+
+                let seconds = days_from_civil(year, month, day) * i64::from(SECONDS_PER_DAY)
+                    + hour * 3600
+                    + minute * 60
+                    + second
+                    - offset;
+                AuditTimestamp::new(u64::try_from(seconds).ok()?, nanoseconds.unwrap_or(0))
+            },
+        )
+        .parse(input)
+}
+
+// The calendar conversions are implemented here rather than with `chrono`, `jiff` or `time`,
+// so `Display` and `FromStr` are available without any of them, which are optional features.
+// They only cover dates after the Unix epoch in UTC, which take a couple of well-known formulas.
+
+/// Days since the Unix epoch of a date in the proleptic Gregorian calendar.
+// Ref: https://howardhinnant.github.io/date_algorithms.html#days_from_civil
+fn days_from_civil(year: i64, month: u32, day: u32) -> i64 {
+    let year = if month <= 2 { year - 1 } else { year };
+    let era = year.div_euclid(400);
+    let year_of_era = year.rem_euclid(400);
+    let month_from_march = i64::from((month + 9) % 12);
+    let day_of_year = (153 * month_from_march + 2) / 5 + i64::from(day) - 1;
+    let day_of_era = year_of_era * 365 + year_of_era / 4 - year_of_era / 100 + day_of_year;
+    era * 146_097 + day_of_era - 719_468
+}
+
+/// Date in the proleptic Gregorian calendar of the given days since the Unix epoch.
+// Ref: https://howardhinnant.github.io/date_algorithms.html#civil_from_days
+fn civil_from_days(days: i64) -> (i64, u32, u32) {
+    let days = days + 719_468;
+    let era = days.div_euclid(146_097);
+    let day_of_era = days.rem_euclid(146_097);
+    let year_of_era =
+        (day_of_era - day_of_era / 1460 + day_of_era / 36_524 - day_of_era / 146_096) / 365;
+    let day_of_year = day_of_era - (365 * year_of_era + year_of_era / 4 - year_of_era / 100);
+    let month_from_march = (5 * day_of_year + 2) / 153;
+    let day = u32::try_from(day_of_year - (153 * month_from_march + 2) / 5 + 1)
+        .expect("day of the month is between 1 and 31");
+    let month = u32::try_from((month_from_march + 2) % 12 + 1).expect("month is between 1 and 12");
+    let year = year_of_era + era * 400 + i64::from(month <= 2);
+    (year, month, day)
+}
+
+#[cfg(test)]
+mod tests {
+    use rstest::rstest;
+
+    use super::*;
+
+    #[rstest]
+    #[case::regular("123.456", 123_456)]
+    #[case::leading_zeroes("001.234", 1234)]
+    #[case::zero_seconds("000.123", 123)]
+    #[case::zero_milliseconds("123.000", 123_000)]
+    #[case::min_value("000.000", 0)]
+    fn test_parse_unix_seconds(#[case] input: &str, #[case] expected: u64) {
+        let (remaining, result) = parse_unix_seconds(input).unwrap();
+        assert!(remaining.is_empty());
+        assert_eq!(result, AuditTimestamp::from_millis(expected));
+    }
+
+    #[rstest]
+    #[case::single_digit("123.4", 400_000_000)]
+    #[case::microseconds("123.456789", 456_789_000)]
+    #[case::nanoseconds("123.456789012", 456_789_012)]
+    fn test_parse_unix_seconds_precision(#[case] input: &str, #[case] expected_nanoseconds: u32) {
+        let (remaining, result) = parse_unix_seconds(input).unwrap();
+        assert!(remaining.is_empty());
+        assert_eq!(
+            result,
+            AuditTimestamp::new(123, expected_nanoseconds).unwrap()
+        );
+    }
+
+    #[rstest]
+    #[case::with_invalid_seconds("abc.123")]
+    #[case::with_invalid_milliseconds("123.abc")]
+    #[case::without_milliseconds("123")]
+    #[case::two_consecutive_dots("123..456")]
+    #[case::non_numeric("abc")]
+    #[case::empty("")]
+    fn test_parse_unix_seconds_fails(#[case] input: &str) {
+        assert!(parse_unix_seconds(input).is_err());
+    }
+
+    #[rstest]
+    #[case::milliseconds("123", 123_000_000)]
+    #[case::single_digit("1", 100_000_000)]
+    #[case::microseconds("123456", 123_456_000)]
+    #[case::nanoseconds("123456789", 123_456_789)]
+    #[case::truncated("1234567891", 123_456_789)]
+    #[case::leading_zeroes("001", 1_000_000)]
+    fn test_parse_fraction_nanoseconds(#[case] input: &str, #[case] expected: u32) {
+        let (remaining, result) = parse_fraction_nanoseconds(input).unwrap();
+        assert!(remaining.is_empty());
+        assert_eq!(result, expected);
+    }
+
+    #[rstest]
+    #[case::non_numeric("abc")]
+    #[case::empty("")]
+    fn test_parse_fraction_nanoseconds_fails(#[case] input: &str) {
+        assert!(parse_fraction_nanoseconds(input).is_err());
+    }
+
+    #[rstest]
+    #[case::epoch(AuditTimestamp::default(), "1970-01-01T00:00:00.000Z")]
+    #[case::milliseconds(
+        AuditTimestamp::from_millis(1_725_039_526_208),
+        "2024-08-30T17:38:46.208Z"
+    )]
+    #[case::microseconds(AuditTimestamp::new(1_725_039_526, 208_001_000).unwrap(), "2024-08-30T17:38:46.208001Z")]
+    #[case::nanoseconds(AuditTimestamp::new(1_725_039_526, 208_000_001).unwrap(), "2024-08-30T17:38:46.208000001Z")]
+    #[case::leap_day(AuditTimestamp::new(951_782_400, 0).unwrap(), "2000-02-29T00:00:00.000Z")]
+    fn test_display_and_parse(#[case] timestamp: AuditTimestamp, #[case] expected: &str) {
+        assert_eq!(timestamp.to_string(), expected);
+        assert_eq!(expected.parse::<AuditTimestamp>().unwrap(), timestamp);
+    }
+
+    #[rstest]
+    #[case::without_fraction("2024-08-30T17:38:46Z", AuditTimestamp::new(1_725_039_526, 0).unwrap())]
+    #[case::positive_offset(
+        "2024-08-30T19:38:46.208+02:00",
+        AuditTimestamp::from_millis(1_725_039_526_208)
+    )]
+    #[case::negative_offset(
+        "2024-08-30T16:38:46.208-01:00",
+        AuditTimestamp::from_millis(1_725_039_526_208)
+    )]
+    fn test_parse_rfc3339(#[case] input: &str, #[case] expected: AuditTimestamp) {
+        assert_eq!(input.parse::<AuditTimestamp>().unwrap(), expected);
+    }
+
+    #[rstest]
+    #[case::before_epoch("1969-12-31T23:59:59Z")]
+    #[case::invalid_month("2024-13-30T17:38:46Z")]
+    #[case::without_offset("2024-08-30T17:38:46")]
+    #[case::trailing_data("2024-08-30T17:38:46Zfoo")]
+    #[case::empty("")]
+    fn test_parse_rfc3339_fails(#[case] input: &str) {
+        assert!(input.parse::<AuditTimestamp>().is_err());
+    }
+
+    #[test]
+    fn test_new_rejects_overflowing_nanoseconds() {
+        assert_eq!(AuditTimestamp::new(0, NANOSECONDS_PER_SECOND), None);
+    }
+
+    #[test]
+    fn test_to_system_time() {
+        let timestamp = AuditTimestamp::new(1_725_039_526, 208_000_001).unwrap();
+        let expected = UNIX_EPOCH + Duration::new(1_725_039_526, 208_000_001);
+        assert_eq!(timestamp.to_system_time(), Some(expected));
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn test_serde_rfc3339() {
+        #[derive(serde::Serialize, serde::Deserialize)]
+        struct Event {
+            #[serde(with = "rfc3339")]
+            timestamp: AuditTimestamp,
+        }
+
+        let event = Event {
+            timestamp: AuditTimestamp::from_millis(1_725_039_526_208),
+        };
+        insta::assert_json_snapshot!(event, @r#"
+        {
+          "timestamp": "2024-08-30T17:38:46.208Z"
+        }
+        "#);
+        insta::assert_json_snapshot!(event.timestamp, @r#""1725039526.208""#);
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn test_serialize_timestamp_format() {
+        let timestamp = AuditTimestamp::new(1_725_039_526, 208_000_001).unwrap();
+        let formatted = |format| FormattedTimestamp { timestamp, format };
+
+        insta::assert_json_snapshot!(formatted(TimestampFormat::UnixSeconds), @r#""1725039526.208000001""#);
+        insta::assert_json_snapshot!(formatted(TimestampFormat::UnixMillis), @"1725039526208");
+        insta::assert_json_snapshot!(formatted(TimestampFormat::Rfc3339), @r#""2024-08-30T17:38:46.208000001Z""#);
+    }
+
+    #[cfg(feature = "serde")]
+    #[rstest]
+    #[case::unix_seconds("1725039526.208000001", AuditTimestamp::new(1_725_039_526, 208_000_001).unwrap())]
+    #[case::rfc3339(
+        "2024-08-30T17:38:46.208Z",
+        AuditTimestamp::from_millis(1_725_039_526_208)
+    )]
+    fn test_deserialize_str(#[case] input: &str, #[case] expected: AuditTimestamp) {
+        use serde::de::value::{Error, StrDeserializer};
+
+        let deserializer = StrDeserializer::<Error>::new(input);
+        assert_eq!(AuditTimestamp::deserialize(deserializer).unwrap(), expected);
+    }
+
+    #[cfg(feature = "chrono")]
+    #[test]
+    fn test_to_chrono() {
+        let timestamp = AuditTimestamp::from_millis(1_725_039_526_208);
+        assert_eq!(
+            timestamp.to_chrono().unwrap().timestamp_millis(),
+            1_725_039_526_208
+        );
+    }
+
+    #[cfg(feature = "jiff")]
+    #[test]
+    fn test_to_jiff() {
+        let timestamp = AuditTimestamp::from_millis(1_725_039_526_208);
+        assert_eq!(
+            timestamp.to_jiff().unwrap().as_millisecond(),
+            1_725_039_526_208
+        );
+    }
+
+    #[cfg(feature = "time")]
+    #[test]
+    fn test_to_time() {
+        let timestamp = AuditTimestamp::new(1_725_039_526, 208_000_001).unwrap();
+        assert_eq!(
+            timestamp.to_time().unwrap().unix_timestamp_nanos(),
+            1_725_039_526_208_000_001
+        );
+    }
+}
@@ -1,6 +1,9 @@
 use std::{fs, path::PathBuf};
 
-use auditd_parser::{AuditdRecord, ParserError};
+use auditd_parser::{
+    AuditLogReader, AuditdRecord, EventAggregator, InterpretOptions, ParseOptions, ParserError,
+    TimestampFormat,
+};
 use erased_serde::Serialize;
 use rstest::rstest;
 
@@ -8,15 +11,14 @@ use rstest::rstest;
 fn test_log_data(#[files("tests/data/**/*.log")] log_file: PathBuf) {
     let log_file_path = log_file.to_string_lossy().into_owned();
     let log_file_content = fs::read_to_string(log_file).unwrap();
-    let logs = log_file_content
-        .lines()
-        .enumerate()
-        .map(|(index, line)| (index + 1, line))
-        .filter(|(_line_number, line)| !line.is_empty() && !line.starts_with('#'))
-        .collect::<Vec<_>>();
-
-    for (line_number, log) in logs {
-        let maybe_record = log.parse::<AuditdRecord>();
+    let logs = log_file_content.lines().collect::<Vec<_>>();
+    let records = AuditLogReader::new(log_file_content.as_bytes()).skip_comments(true);
+
+    for read_record in records {
+        let read_record = read_record.unwrap();
+        let line_number = read_record.line_number;
+        let maybe_record = read_record.record;
+        let log = logs[line_number - 1];
         let log_identifier = get_log_identifier(log);
 
         // Unwrap the Ok variant but leave the Err variant as-is
@@ -39,6 +41,66 @@ fn test_log_data(#[files("tests/data/**/*.log")] log_file: PathBuf) {
     }
 }
 
+#[rstest]
+#[case::sockaddr(
+    "type=SOCKADDR msg=audit(1725039526.208:52): saddr=02000050A9FEA9FE uid=0 comm=\"cat\""
+)]
+#[case::execve("type=EXECVE msg=audit(1725039526.208:53): argc=2 a0=\"ls\" a1=\"-l\"")]
+fn test_with_raw_values(#[case] log: &str) {
+    let options = ParseOptions {
+        interpret: InterpretOptions::new().keep_raw_values(true),
+        ..Default::default()
+    };
+    let record = AuditdRecord::parse_with(log, &options).unwrap();
+
+    insta::with_settings!(
+        {
+            info => &log,
+            snapshot_suffix => get_log_identifier(log),
+        },
+        {
+            insta::assert_json_snapshot!(record.with_raw_values());
+        }
+    );
+}
+
+#[rstest]
+#[case::unix_seconds(TimestampFormat::UnixSeconds)]
+#[case::unix_millis(TimestampFormat::UnixMillis)]
+#[case::rfc3339(TimestampFormat::Rfc3339)]
+fn test_timestamp_format(#[case] format: TimestampFormat) {
+    let logs = [
+        "type=SYSCALL msg=audit(1725039526.208000001:52): arch=c000003e syscall=59 success=yes",
+        "type=EOE msg=audit(1725039526.208000001:52):",
+    ];
+    let mut aggregator = EventAggregator::new();
+    let mut events = Vec::new();
+    for log in logs {
+        events.extend(aggregator.push(log.parse().unwrap()));
+    }
+    // Raw values are kept for the snapshot of `with_raw_values`
+    let options = ParseOptions {
+        interpret: InterpretOptions::new().keep_raw_values(true),
+        ..Default::default()
+    };
+    let record = AuditdRecord::parse_with(logs[0], &options).unwrap();
+
+    insta::with_settings!(
+        {
+            info => &logs[0],
+            snapshot_suffix => format!("{format:?}"),
+        },
+        {
+            insta::assert_json_snapshot!("timestamp_format_record", record.with_timestamp_format(format));
+            insta::assert_json_snapshot!("timestamp_format_event", events[0].with_timestamp_format(format));
+            insta::assert_json_snapshot!(
+                "timestamp_format_raw_values",
+                record.with_raw_values().timestamp_format(format)
+            );
+        }
+    );
+}
+
 fn get_log_identifier(log: &str) -> String {
     let log_md5 = md5::compute(log);
     format!("{log_md5:x}")